@@ -0,0 +1,213 @@
+use serde::Serialize;
+use std::fmt;
+
+/// Whether the binary was built with the counting allocator (`--features alloc-stats`)
+pub const ENABLED: bool = cfg!(feature = "alloc-stats");
+
+/// Heap activity of the current thread during one solve
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct AllocStats {
+    /// Number of allocations, counting each reallocation as one
+    pub allocations: u64,
+    /// Total bytes requested by those allocations
+    pub bytes: u64,
+    /// Most bytes live at once, above what was live when the solve started
+    pub peak: u64,
+}
+
+impl fmt::Display for AllocStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocation{}, {} allocated, {} peak",
+            self.allocations,
+            if self.allocations == 1 { "" } else { "s" },
+            format_bytes(self.bytes),
+            format_bytes(self.peak)
+        )
+    }
+}
+
+/// Formats a byte count with a binary unit (`512 B`, `1.5 KiB`, `12.0 MiB`)
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", value, UNITS[unit])
+}
+
+/// Runs `f` and counts the allocations it makes on the current thread
+///
+/// # Returns
+/// `f`'s result, and the statistics if the counting allocator is enabled
+#[cfg(feature = "alloc-stats")]
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    let before = counting::start();
+    let value = f();
+    let after = counting::snapshot();
+
+    let stats = AllocStats {
+        allocations: after.allocations - before.allocations,
+        bytes: after.bytes - before.bytes,
+        peak: u64::try_from(after.peak - before.live).unwrap_or(0),
+    };
+    (value, Some(stats))
+}
+
+/// Runs `f`; without the `alloc-stats` feature there is nothing to count
+///
+/// # Returns
+/// `f`'s result and `None`
+#[cfg(not(feature = "alloc-stats"))]
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    (f(), None)
+}
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static GLOBAL: counting::CountingAllocator = counting::CountingAllocator;
+
+/// The system allocator, with per-thread counters
+///
+/// Counters are thread-local so parts solved concurrently (`--jobs`) don't
+/// count each other's allocations.
+#[cfg(feature = "alloc-stats")]
+mod counting {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    pub struct CountingAllocator;
+
+    #[derive(Clone, Copy)]
+    pub struct Counters {
+        pub allocations: u64,
+        pub bytes: u64,
+        /// Signed: memory allocated on another thread may be freed on this one
+        pub live: i64,
+        pub peak: i64,
+    }
+
+    thread_local! {
+        static COUNTERS: Cell<Counters> = const {
+            Cell::new(Counters {
+                allocations: 0,
+                bytes: 0,
+                live: 0,
+                peak: 0,
+            })
+        };
+    }
+
+    /// Updates this thread's counters (a no-op while the thread is shutting down)
+    fn update(f: impl FnOnce(&mut Counters)) {
+        let _ = COUNTERS.try_with(|cell| {
+            let mut counters = cell.get();
+            f(&mut counters);
+            cell.set(counters);
+        });
+    }
+
+    fn on_alloc(size: usize) {
+        update(|c| {
+            c.allocations += 1;
+            c.bytes += size as u64;
+            c.live += size as i64;
+            c.peak = c.peak.max(c.live);
+        });
+    }
+
+    fn on_free(size: usize) {
+        update(|c| c.live -= size as i64);
+    }
+
+    /// The current counters
+    pub fn snapshot() -> Counters {
+        COUNTERS.with(Cell::get)
+    }
+
+    /// Restarts the peak from what is live now and returns the counters
+    pub fn start() -> Counters {
+        update(|c| c.peak = c.live);
+        snapshot()
+    }
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = unsafe { System.alloc(layout) };
+            if !ptr.is_null() {
+                on_alloc(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = unsafe { System.alloc_zeroed(layout) };
+            if !ptr.is_null() {
+                on_alloc(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            unsafe { System.dealloc(ptr, layout) };
+            on_free(layout.size());
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+            if !new_ptr.is_null() {
+                on_free(layout.size());
+                on_alloc(new_size);
+            }
+            new_ptr
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "alloc-stats")]
+    #[test]
+    fn counts_allocations_and_the_peak_above_the_starting_heap() {
+        // Live before the measurement, so not part of the peak
+        let held = vec![0u8; 1 << 20];
+
+        let (len, stats) = measure(|| {
+            let big = std::hint::black_box(vec![0u8; 4096]);
+            drop(big);
+            let small = std::hint::black_box(vec![0u8; 1024]);
+            small.len()
+        });
+        let stats = stats.unwrap();
+
+        assert_eq!(len, 1024);
+        assert!(stats.allocations >= 2);
+        assert!(stats.bytes >= 4096 + 1024);
+        assert!((4096..1 << 20).contains(&stats.peak));
+        drop(held);
+    }
+
+    #[cfg(not(feature = "alloc-stats"))]
+    #[test]
+    fn counts_nothing_without_the_feature() {
+        let (len, stats) = measure(|| vec![0u8; 4096].len());
+        assert_eq!(len, 4096);
+        assert_eq!(stats, None);
+    }
+
+    #[test]
+    fn formats_byte_counts() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(12 << 20), "12.0 MiB");
+    }
+}
@@ -0,0 +1,329 @@
+use crate::submit::Verdict;
+use crate::utils::{calendar, crypto, input_path, AocError, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Default Advent of Code server
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// User-Agent sent with every request, as requested by the AoC maintainers
+pub const USER_AGENT: &str = concat!(
+    "aoc-2025/",
+    env!("CARGO_PKG_VERSION"),
+    " (+https://github.com/ghostcipher1/advent-of-code-2025)"
+);
+
+/// Environment variable holding the session cookie value
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+/// Directory (relative to the project root) for local tool state
+pub const STATE_DIR: &str = ".aoc";
+
+/// Minimum spacing between requests to the AoC server, across invocations
+pub const MIN_REQUEST_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Loads the AoC session cookie value
+///
+/// Looked up, in order, from the `AOC_SESSION` environment variable,
+/// `session_file` (`session_file` in `aoc.toml`), `.aoc/session` in the
+/// project root, and `~/.config/aoc/session`.
+///
+/// # Errors
+/// Returns `AocError::RequestError` if no session token is configured
+pub fn load_session(root: &Path, session_file: Option<&Path>) -> Result<String> {
+    if let Ok(token) = std::env::var(SESSION_ENV) {
+        if !token.trim().is_empty() {
+            return Ok(token.trim().to_string());
+        }
+    }
+
+    let mut candidates: Vec<PathBuf> = session_file.into_iter().map(Path::to_path_buf).collect();
+    candidates.push(root.join(STATE_DIR).join("session"));
+    if let Some(home) = std::env::var_os("HOME") {
+        candidates.push(PathBuf::from(home).join(".config/aoc/session"));
+    }
+
+    for path in candidates {
+        if let Ok(token) = fs::read_to_string(&path) {
+            if !token.trim().is_empty() {
+                return Ok(token.trim().to_string());
+            }
+        }
+    }
+
+    Err(AocError::RequestError(format!(
+        "No session token found: set {} or write it to {}/session",
+        SESSION_ENV, STATE_DIR
+    )))
+}
+
+/// A minimal client for the Advent of Code website
+pub struct AocClient {
+    base_url: String,
+    session: String,
+    state_dir: PathBuf,
+    agent: ureq::Agent,
+}
+
+impl AocClient {
+    /// Creates a client
+    ///
+    /// # Arguments
+    /// * `base_url` - Server to talk to (e.g. `DEFAULT_BASE_URL` or a local mock)
+    /// * `session` - Session cookie value
+    /// * `root` - Project root, whose `.aoc/` directory stores the rate-limit stamp
+    pub fn new(base_url: &str, session: String, root: &Path) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+
+        AocClient {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            state_dir: root.join(STATE_DIR),
+            agent,
+        }
+    }
+
+    /// URL of a day's puzzle input
+    pub fn input_url(&self, year: u16, day: u8) -> String {
+        format!("{}/{}/day/{}/input", self.base_url, year, day)
+    }
+
+    /// Downloads a day's puzzle input
+    ///
+    /// # Errors
+    /// Returns `AocError::RequestError` if the request fails or is rejected
+    pub fn get_input(&self, year: u16, day: u8) -> Result<String> {
+        self.get(&self.input_url(year, day))
+    }
+
+    /// URL of a day's puzzle description page
+    pub fn puzzle_url(&self, year: u16, day: u8) -> String {
+        format!("{}/{}/day/{}", self.base_url, year, day)
+    }
+
+    /// Downloads a day's puzzle description page
+    ///
+    /// The request is authenticated, so part 2 is included once unlocked.
+    ///
+    /// # Errors
+    /// Returns `AocError::RequestError` if the request fails or is rejected
+    pub fn get_puzzle_page(&self, year: u16, day: u8) -> Result<String> {
+        self.get(&self.puzzle_url(year, day))
+    }
+
+    /// URL of a day's answer endpoint
+    pub fn answer_url(&self, year: u16, day: u8) -> String {
+        format!("{}/{}/day/{}/answer", self.base_url, year, day)
+    }
+
+    /// Submits an answer for a day and part
+    ///
+    /// # Returns
+    /// The server's verdict, parsed from the response page
+    ///
+    /// # Errors
+    /// Returns `AocError::RequestError` if the request fails or is rejected
+    pub fn submit_answer(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<Verdict> {
+        let url = self.answer_url(year, day);
+        self.throttle()?;
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+        let page = read_response("POST", &url, response)?;
+        Ok(Verdict::from_response(&page))
+    }
+
+    /// Sends an authenticated GET request and returns the response body
+    fn get(&self, url: &str) -> Result<String> {
+        self.throttle()?;
+        let response = self
+            .agent
+            .get(url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+        read_response("GET", url, response)
+    }
+
+    /// Waits until `MIN_REQUEST_INTERVAL` has passed since the last request
+    ///
+    /// The time of the last request is stored in `.aoc/last-request` so
+    /// the spacing also holds across separate runs of the binary.
+    fn throttle(&self) -> Result<()> {
+        let stamp = self.state_dir.join("last-request");
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+
+        if let Some(last) = fs::read_to_string(&stamp)
+            .ok()
+            .and_then(|s| s.trim().parse::<u64>().ok())
+        {
+            let elapsed = now.saturating_sub(Duration::from_millis(last));
+            if elapsed < MIN_REQUEST_INTERVAL {
+                thread::sleep(MIN_REQUEST_INTERVAL - elapsed);
+            }
+        }
+
+        fs::create_dir_all(&self.state_dir)?;
+        let sent_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        fs::write(&stamp, sent_at.as_millis().to_string())?;
+        Ok(())
+    }
+}
+
+/// Turns a ureq response into a body or a descriptive error
+fn read_response(
+    method: &str,
+    url: &str,
+    response: std::result::Result<ureq::Response, ureq::Error>,
+) -> Result<String> {
+    match response {
+        Ok(response) => response.into_string().map_err(AocError::from),
+        Err(ureq::Error::Status(status, response)) => {
+            let reason = match status {
+                400 | 500 => "the session token is missing, invalid or expired".to_string(),
+                404 => "the puzzle hasn't been released yet".to_string(),
+                429 => match response.header("Retry-After") {
+                    Some(wait) => format!("rate limited, retry after {} seconds", wait),
+                    None => "rate limited, try again later".to_string(),
+                },
+                _ => response.status_text().to_string(),
+            };
+            Err(AocError::RequestError(format!(
+                "{} {} returned {}: {}",
+                method, url, status, reason
+            )))
+        }
+        Err(e) => Err(AocError::RequestError(format!(
+            "{} {} failed: {}",
+            method, url, e
+        ))),
+    }
+}
+
+/// What `fetch_input` did
+#[derive(Debug)]
+pub enum FetchOutcome {
+    /// The input was already cached; nothing was downloaded. Holds any
+    /// files that were filled in from the cached copy.
+    Cached(Vec<PathBuf>),
+    /// The input was downloaded and written to these files
+    Downloaded(Vec<PathBuf>),
+}
+
+/// Makes sure the real input files for a day exist, downloading at most once
+///
+/// The puzzle input is the same for both parts, so it is written to both
+/// `inputs/YYYY/dayNNp1.txt` and `inputs/YYYY/dayNNp2.txt` (the names `read_input`
+/// expects), or only to the first on a day with one part (see
+/// `calendar::parts_in_day`). If either file already has content, or an encrypted copy
+/// exists, it is reused and the server is never contacted. Empty files
+/// (e.g. from `new`) don't count.
+///
+/// # Arguments
+/// * `root` - The project root containing `inputs/`
+/// * `year` - The event year
+/// * `day` - The day number (within the year's `calendar::valid_days`)
+/// * `base_url` - Server to download from if the input isn't cached
+/// * `session_file` - Extra session cookie file (see `load_session`)
+///
+/// # Errors
+/// Returns `AocError::InvalidYear` or `AocError::InvalidDay` for a day
+/// outside the calendar, `AocError::NotReleased` if the input would have to
+/// be downloaded before the puzzle unlocks, `AocError::RequestError` if downloading fails
+/// (including a missing session token), or `AocError::IoError` if the files
+/// can't be written
+pub fn fetch_input(
+    root: &Path,
+    year: u16,
+    day: u8,
+    base_url: &str,
+    session_file: Option<&Path>,
+) -> Result<FetchOutcome> {
+    let paths = (1..=calendar::parts_in_day(year, day)?)
+        .map(|part| Ok(root.join(input_path(year, day, part, false)?)))
+        .collect::<Result<Vec<_>>>()?;
+
+    // `read_input` decrypts an encrypted copy on the fly, so that counts as cached too
+    if paths
+        .iter()
+        .any(|path| !path.exists() && crypto::encrypted_path(path).exists())
+    {
+        return Ok(FetchOutcome::Cached(Vec::new()));
+    }
+
+    let cached = paths
+        .iter()
+        .find_map(|path| fs::read_to_string(path).ok().filter(|s| !s.is_empty()));
+
+    let (input, downloaded) = match cached {
+        Some(input) => (input, false),
+        None => {
+            calendar::check_released(year, day)?;
+            let client = AocClient::new(base_url, load_session(root, session_file)?, root);
+            (client.get_input(year, day)?, true)
+        }
+    };
+
+    let mut written = Vec::new();
+    for path in &paths {
+        let has_content = fs::metadata(path).map(|m| m.len() > 0).unwrap_or(false);
+        if !has_content {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(path, &input)?;
+            written.push(path.clone());
+        }
+    }
+
+    Ok(if downloaded {
+        FetchOutcome::Downloaded(written)
+    } else {
+        FetchOutcome::Cached(written)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A base URL nothing listens on, so any download attempt fails
+    const OFFLINE: &str = "http://127.0.0.1:9";
+
+    #[test]
+    fn cached_input_fills_in_the_second_part() {
+        let root = tempfile::tempdir().unwrap();
+        let part1 = root.path().join(input_path(2025, 11, 1, false).unwrap());
+        fs::create_dir_all(part1.parent().unwrap()).unwrap();
+        fs::write(&part1, "input").unwrap();
+
+        let outcome = fetch_input(root.path(), 2025, 11, OFFLINE, None).unwrap();
+        let part2 = root.path().join(input_path(2025, 11, 2, false).unwrap());
+        assert!(
+            matches!(outcome, FetchOutcome::Cached(ref written) if written[..] == [part2.clone()])
+        );
+        assert_eq!(fs::read_to_string(part2).unwrap(), "input");
+    }
+
+    #[test]
+    fn last_day_only_uses_its_single_part() {
+        let root = tempfile::tempdir().unwrap();
+        let part1 = root.path().join(input_path(2025, 12, 1, false).unwrap());
+        fs::create_dir_all(part1.parent().unwrap()).unwrap();
+        fs::write(&part1, "input").unwrap();
+
+        let outcome = fetch_input(root.path(), 2025, 12, OFFLINE, None).unwrap();
+        assert!(matches!(outcome, FetchOutcome::Cached(ref written) if written.is_empty()));
+        assert!(!part1.with_file_name("day12p2.txt").exists());
+    }
+}
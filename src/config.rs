@@ -0,0 +1,366 @@
+use crate::days::SolverParams;
+use crate::logging::LogFormat;
+use crate::submit::SubmissionHistory;
+use crate::utils::{calendar, input, AocError, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Name of the configuration file, in the project root or `~/.config/aoc/`
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+/// Settings from `aoc.toml`
+///
+/// Every field is optional: anything left out falls back to the built-in
+/// default, and command-line flags override whatever the file says.
+///
+/// ```toml
+/// year = 2025
+/// sample = true
+/// input_dir = "inputs"
+/// log_format = "text"
+/// format = "text"
+/// timeout = "30s"
+/// answers = ".aoc/submissions.tsv"
+/// session_file = "/home/me/.secrets/aoc-session"
+///
+/// [day1]
+/// dial_size = 100
+///
+/// [day3]
+/// k = 12
+///
+/// [day4]
+/// threshold = 4
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Event year used when `--year` / `AOC_YEAR` aren't given
+    pub year: Option<u16>,
+    /// Use the sample inputs unless `--real` is given
+    pub sample: Option<bool>,
+    /// Directory holding the `YYYY/` input directories
+    pub input_dir: Option<PathBuf>,
+    /// Format of the log lines on stderr
+    pub log_format: Option<LogFormat>,
+    /// Format of the results on stdout
+    pub format: Option<OutputFormat>,
+    /// Per-part time limit, e.g. `"30s"` (see `parse_duration`)
+    pub timeout: Option<String>,
+    /// Submission history file used by `submit`
+    pub answers: Option<PathBuf>,
+    /// File holding the session cookie, tried after `AOC_SESSION`
+    pub session_file: Option<PathBuf>,
+    pub day1: Day1Config,
+    pub day3: Day3Config,
+    pub day4: Day4Config,
+}
+
+/// How `run`, `test` and `bench` print their results on stdout
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    /// Headings, answers and tables
+    Text,
+    /// One JSON object per part, and nothing else
+    Json,
+}
+
+/// `[day1]` parameters for the REPL and dashboard (see `SolverParams`)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Day1Config {
+    /// Number of positions on the dial
+    pub dial_size: Option<i32>,
+}
+
+/// `[day3]` parameters for the REPL and dashboard (see `SolverParams`)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Day3Config {
+    /// Batteries turned on per bank in part 2
+    pub k: Option<usize>,
+}
+
+/// `[day4]` parameters for the REPL and dashboard (see `SolverParams`)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Day4Config {
+    /// A roll is accessible with fewer than this many neighbouring rolls
+    pub threshold: Option<usize>,
+}
+
+impl Config {
+    /// Paths searched for `aoc.toml`, lowest precedence first
+    ///
+    /// The user file is `$XDG_CONFIG_HOME/aoc/aoc.toml` (or
+    /// `~/.config/aoc/aoc.toml`); the project file is `aoc.toml` in `root`.
+    pub fn search_paths(root: &Path) -> Vec<PathBuf> {
+        let config_home = std::env::var_os("XDG_CONFIG_HOME")
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")));
+
+        let mut paths = Vec::new();
+        if let Some(config_home) = config_home {
+            paths.push(config_home.join("aoc").join(CONFIG_FILE));
+        }
+        paths.push(root.join(CONFIG_FILE));
+        paths
+    }
+
+    /// Loads and merges every config file that exists
+    ///
+    /// Settings in the project file override those in the user file.
+    ///
+    /// # Returns
+    /// The merged settings and the files they were read from
+    ///
+    /// # Errors
+    /// Returns `AocError::IoError` if a file exists but can't be read, or
+    /// `AocError::ParseError` if it isn't valid (unknown keys included)
+    pub fn load(root: &Path) -> Result<(Config, Vec<PathBuf>)> {
+        let mut config = Config::default();
+        let mut loaded = Vec::new();
+
+        for path in Config::search_paths(root) {
+            let contents = match fs::read_to_string(&path) {
+                Ok(contents) => contents,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(e) => return Err(e.into()),
+            };
+            config = Config::parse(&contents, &path)?.or(config);
+            loaded.push(path);
+        }
+
+        Ok((config, loaded))
+    }
+
+    /// Parses and validates one config file
+    ///
+    /// # Errors
+    /// Returns `AocError::ParseError` naming `path` if the TOML is invalid,
+    /// has unknown keys, or a value is out of range
+    pub fn parse(contents: &str, path: &Path) -> Result<Config> {
+        let invalid = |message: String| {
+            AocError::ParseError(format!(
+                "Invalid config file {}: {}",
+                path.display(),
+                message
+            ))
+        };
+
+        let config: Config =
+            toml::from_str(contents).map_err(|e| invalid(e.message().to_string()))?;
+        if let Some(timeout) = &config.timeout {
+            parse_duration(timeout).map_err(|e| invalid(format!("timeout: {}", e)))?;
+        }
+        if config.day1.dial_size.is_some_and(|size| size < 1) {
+            return Err(invalid("day1.dial_size must be at least 1".to_string()));
+        }
+        Ok(config)
+    }
+
+    /// Fills every setting `self` leaves out from `fallback`
+    pub fn or(self, fallback: Config) -> Config {
+        Config {
+            year: self.year.or(fallback.year),
+            sample: self.sample.or(fallback.sample),
+            input_dir: self.input_dir.or(fallback.input_dir),
+            log_format: self.log_format.or(fallback.log_format),
+            format: self.format.or(fallback.format),
+            timeout: self.timeout.or(fallback.timeout),
+            answers: self.answers.or(fallback.answers),
+            session_file: self.session_file.or(fallback.session_file),
+            day1: Day1Config {
+                dial_size: self.day1.dial_size.or(fallback.day1.dial_size),
+            },
+            day3: Day3Config {
+                k: self.day3.k.or(fallback.day3.k),
+            },
+            day4: Day4Config {
+                threshold: self.day4.threshold.or(fallback.day4.threshold),
+            },
+        }
+    }
+
+    /// The built-in value of every setting
+    ///
+    /// `timeout` and `session_file` have no default and stay `None`.
+    pub fn defaults(root: &Path) -> Config {
+        let params = SolverParams::default();
+        Config {
+            year: Some(calendar::DEFAULT_YEAR),
+            sample: Some(false),
+            input_dir: Some(PathBuf::from(input::DEFAULT_INPUT_DIR)),
+            log_format: Some(LogFormat::Text),
+            format: Some(OutputFormat::Text),
+            timeout: None,
+            answers: Some(SubmissionHistory::default_path(root)),
+            session_file: None,
+            day1: Day1Config {
+                dial_size: Some(params.dial_size),
+            },
+            day3: Day3Config { k: Some(params.k) },
+            day4: Day4Config {
+                threshold: Some(params.threshold),
+            },
+        }
+    }
+
+    /// Makes the input directory take effect
+    pub fn apply(&self) {
+        if let Some(dir) = &self.input_dir {
+            input::set_input_dir(dir);
+        }
+    }
+
+    /// The `[dayN]` parameters, with the puzzle's own values for any left out
+    ///
+    /// These are only handed to the REPL and the dashboard; the registered
+    /// solvers don't read them.
+    pub fn solver_params(&self) -> SolverParams {
+        let defaults = SolverParams::default();
+        SolverParams {
+            dial_size: self.day1.dial_size.unwrap_or(defaults.dial_size),
+            k: self.day3.k.unwrap_or(defaults.k),
+            threshold: self.day4.threshold.unwrap_or(defaults.threshold),
+        }
+    }
+
+    /// The `timeout` setting as a `Duration`
+    ///
+    /// Already validated by `parse`, so an invalid value can only come from
+    /// a `Config` built in code; it is treated as no timeout.
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+            .as_deref()
+            .and_then(|timeout| parse_duration(timeout).ok())
+    }
+
+    /// Renders the settings as `aoc.toml` text
+    ///
+    /// # Errors
+    /// Returns `AocError::ParseError` if a value can't be represented in TOML
+    pub fn to_toml(&self) -> Result<String> {
+        toml::to_string(self)
+            .map_err(|e| AocError::ParseError(format!("Could not render config: {}", e)))
+    }
+}
+
+/// Parses a duration: a number with an optional `ms`, `s` or `m` suffix
+///
+/// A plain number is seconds, so `30`, `30s`, `500ms` and `0.5m` all work.
+///
+/// # Errors
+/// Returns a message describing the problem if the value isn't a positive duration
+pub fn parse_duration(value: &str) -> std::result::Result<Duration, String> {
+    let value = value.trim();
+    let split = value
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(split);
+    let number: f64 = number
+        .parse()
+        .map_err(|_| format!("'{}' is not a duration (try 30s, 500ms or 2m)", value))?;
+    let seconds = match unit.trim() {
+        "ms" => number / 1000.0,
+        "" | "s" => number,
+        "m" => number * 60.0,
+        other => {
+            return Err(format!(
+                "Unknown duration unit '{}' (use ms, s or m)",
+                other
+            ))
+        }
+    };
+    Duration::try_from_secs_f64(seconds)
+        .ok()
+        .filter(|duration| !duration.is_zero())
+        .ok_or_else(|| format!("'{}' is not a positive duration", value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repl::ReplSession;
+
+    #[test]
+    fn parses_duration_units() {
+        assert_eq!(parse_duration("30"), Ok(Duration::from_secs(30)));
+        assert_eq!(parse_duration("30s"), Ok(Duration::from_secs(30)));
+        assert_eq!(parse_duration("500ms"), Ok(Duration::from_millis(500)));
+        assert_eq!(parse_duration("2m"), Ok(Duration::from_secs(120)));
+        assert_eq!(parse_duration(" 0.5m "), Ok(Duration::from_secs(30)));
+    }
+
+    #[test]
+    fn rejects_invalid_durations() {
+        assert!(parse_duration("").is_err());
+        assert!(parse_duration("abc").is_err());
+        assert!(parse_duration("10h").is_err());
+        assert!(parse_duration("0").is_err());
+        assert!(parse_duration("0ms").is_err());
+        assert!(parse_duration("1.2.3s").is_err());
+    }
+
+    #[test]
+    fn project_file_overrides_user_file_and_flags_override_both() {
+        // The only test that reads XDG_CONFIG_HOME
+        let home = tempfile::tempdir().unwrap();
+        let root = tempfile::tempdir().unwrap();
+        std::env::set_var("XDG_CONFIG_HOME", home.path());
+        let user = home.path().join("aoc").join(CONFIG_FILE);
+        fs::create_dir_all(user.parent().unwrap()).unwrap();
+        fs::write(
+            &user,
+            "year = 2024\nsample = true\ntimeout = \"1m\"\n[day1]\ndial_size = 10\n",
+        )
+        .unwrap();
+        fs::write(
+            root.path().join(CONFIG_FILE),
+            "year = 2025\nformat = \"json\"\n[day3]\nk = 3\n",
+        )
+        .unwrap();
+
+        let (files, loaded) = Config::load(root.path()).unwrap();
+        assert_eq!(loaded, [user, root.path().join(CONFIG_FILE)]);
+        let cli = Config {
+            sample: Some(false),
+            timeout: Some("5s".to_string()),
+            ..Config::default()
+        };
+        let config = cli.or(files).or(Config::defaults(root.path()));
+
+        assert_eq!(config.year, Some(2025));
+        assert_eq!(config.sample, Some(false));
+        assert_eq!(config.timeout(), Some(Duration::from_secs(5)));
+        assert_eq!(config.format, Some(OutputFormat::Json));
+        assert_eq!(config.log_format, Some(LogFormat::Text));
+        assert_eq!(config.day1.dial_size, Some(10));
+        assert_eq!(config.day3.k, Some(3));
+        assert_eq!(
+            config.day4.threshold,
+            Some(SolverParams::default().threshold)
+        );
+    }
+
+    #[test]
+    fn day_params_reach_the_repl_but_not_the_registered_solver() {
+        let config = Config::parse("[day1]\ndial_size = 5\n", Path::new(CONFIG_FILE)).unwrap();
+        assert_eq!(config.solver_params().dial_size, 5);
+        assert_eq!(config.solver_params().k, SolverParams::default().k);
+
+        let input = input::read_input(2025, 1, 1, true).unwrap();
+        let registered = crate::days::solve_part1(2025, 1, &input).unwrap();
+        assert_eq!(registered, "3");
+
+        let mut session = ReplSession::load(2025, 1, true, config.solver_params()).unwrap();
+        let output = session.execute("part1").unwrap().unwrap();
+        let experiment = crate::days::y2025::day01::count_zero_stops(&input, 5).unwrap();
+        assert_ne!(experiment.to_string(), registered);
+        assert!(output.starts_with(&format!("Part 1: {} (", experiment)));
+    }
+}
@@ -1,90 +1,183 @@
-use crate::utils::Result;
+use crate::utils::{calendar, panic, AocError, Result};
+use std::path::PathBuf;
+use std::time::SystemTime;
+use tracing::{debug, info_span, instrument};
 
-pub mod day01;
-pub mod day02;
-pub mod day03;
-pub mod day04;
-pub mod day05;
+pub mod y2025;
+
+use y2025::{day01, day03, day04};
+
+/// Puzzle parameters for exploring variations of a puzzle (`[dayN]` in `aoc.toml`)
+///
+/// The defaults are the puzzle's own values. The registered solvers always
+/// use those, so answers that are run, tested or submitted never change;
+/// other values only reach the REPL and the dashboard's visualizations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SolverParams {
+    /// Day 1: number of positions on the dial
+    pub dial_size: i32,
+    /// Day 3: batteries turned on per bank in part 2
+    pub k: usize,
+    /// Day 4: a roll is accessible with fewer than this many neighbouring rolls
+    pub threshold: usize,
+}
+
+impl Default for SolverParams {
+    fn default() -> Self {
+        SolverParams {
+            dial_size: day01::DIAL_SIZE,
+            k: day03::PART2_BATTERIES,
+            threshold: day04::ACCESS_THRESHOLD,
+        }
+    }
+}
 
 /// Type alias for a solve function that takes input and returns a result string
 type SolveFn = fn(&str) -> Result<String>;
 
-/// Registry mapping day numbers to their solve functions
+/// Registry mapping day numbers to their puzzle title and solve functions
 struct DayRegistry {
+    title: Option<&'static str>,
     part1: Option<SolveFn>,
     part2: Option<SolveFn>,
 }
 
-/// Day registry map - add new days here as they are implemented
-fn get_day_registry(day: u8) -> Option<DayRegistry> {
-    match day {
-        1 => Some(DayRegistry {
-            part1: Some(day01::solve_part1),
-            part2: Some(day01::solve_part2),
-        }),
-        2 => Some(DayRegistry {
-            part1: Some(day02::solve_part1),
-            part2: Some(day02::solve_part2),
-        }),
-        3 => Some(DayRegistry {
-            part1: Some(day03::solve_part1),
-            part2: Some(day03::solve_part2),
-        }),
-        4 => Some(DayRegistry {
-            part1: Some(day04::solve_part1),
-            part2: Some(day04::solve_part2),
-        }),
-        5 => Some(DayRegistry {
-            part1: Some(day05::solve_part1),
-            part2: Some(day05::solve_part2),
-        }),
+/// Calendar metadata and implementation status for one puzzle day
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayInfo {
+    pub year: u16,
+    pub day: u8,
+    /// Puzzle title, if the day is registered with one
+    pub title: Option<&'static str>,
+    /// Number of puzzle parts (1 for the event's last day)
+    pub parts: u8,
+    pub part1_implemented: bool,
+    pub part2_implemented: bool,
+    /// When the puzzle unlocks
+    pub unlocks: SystemTime,
+}
+
+/// Year registry map - add other years here, each with its own `yYYYY` module
+fn get_year_registry(year: u16) -> Option<fn(u8) -> Option<DayRegistry>> {
+    match year {
+        y2025::YEAR => Some(y2025::get_day_registry),
         _ => None,
     }
 }
 
-/// Solves Part 1 for the given day
+/// Directory (relative to the project root) holding a year's solver modules
+pub fn source_dir(year: u16) -> PathBuf {
+    PathBuf::from(format!("src/days/y{}", year))
+}
+
+/// Checks whether the registry has solvers for an event year
+pub fn has_solvers(year: u16) -> bool {
+    get_year_registry(year).is_some()
+}
+
+/// Checks that the registry has solvers for an event year
+///
+/// Commands that work on the solver modules themselves (scaffolding,
+/// day-specific queries) only make sense for such a year.
+///
+/// # Errors
+/// Returns `AocError::SolutionError` if no solvers are registered for the year
+pub fn check_solver_year(year: u16) -> Result<()> {
+    if !has_solvers(year) {
+        return Err(AocError::SolutionError(format!(
+            "No solvers are registered for {} ({} doesn't exist)",
+            year,
+            source_dir(year).display()
+        )));
+    }
+    Ok(())
+}
+
+#[instrument(level = "trace")]
+fn get_registry(year: u16, day: u8) -> Result<DayRegistry> {
+    calendar::check_day(year, day)?;
+
+    let registry = get_year_registry(year).and_then(|days| days(day));
+    registry.ok_or_else(|| {
+        AocError::SolutionError(format!("Day {} of {} is not implemented", day, year))
+    })
+}
+
+/// Looks up the calendar metadata and implementation status of a day
+///
+/// # Errors
+/// Returns `AocError::InvalidYear` or `AocError::InvalidDay` if the day is
+/// not part of the event
+pub fn day_info(year: u16, day: u8) -> Result<DayInfo> {
+    let parts = calendar::parts_in_day(year, day)?;
+    let registry = match get_registry(year, day) {
+        Ok(registry) => Some(registry),
+        Err(AocError::SolutionError(_)) => None,
+        Err(e) => return Err(e),
+    };
+
+    Ok(DayInfo {
+        year,
+        day,
+        title: registry.as_ref().and_then(|r| r.title),
+        parts,
+        part1_implemented: registry.as_ref().is_some_and(|r| r.part1.is_some()),
+        part2_implemented: parts > 1 && registry.as_ref().is_some_and(|r| r.part2.is_some()),
+        unlocks: calendar::unlock_time(year, day)?,
+    })
+}
+
+/// Solves Part 1 for the given year and day
 ///
 /// # Arguments
-/// * `day` - The day number (1-25)
+/// * `year` - The event year
+/// * `day` - The day number (within the year's `calendar::valid_days`)
 /// * `input` - The input string for the day's challenge
 ///
 /// # Returns
 /// The solution as a String
 ///
 /// # Errors
-/// Returns an error if the day is not implemented or if solving fails
-pub fn solve_part1(day: u8, input: &str) -> Result<String> {
-    let registry = get_day_registry(day)
-        .ok_or_else(|| crate::utils::AocError::InvalidDay(day))?;
-    
-    registry
-        .part1
-        .ok_or_else(|| {
-            crate::utils::AocError::SolutionError(format!("Part 1 not implemented for day {}", day))
-        })?
-        (input)
+/// Returns an error if the day is not part of the event, is not
+/// implemented, or if solving fails; a panicking solver is reported as
+/// `AocError::SolverPanic`
+pub fn solve_part1(year: u16, day: u8, input: &str) -> Result<String> {
+    let registry = get_registry(year, day)?;
+
+    let solver = registry.part1.ok_or_else(|| {
+        AocError::SolutionError(format!("Part 1 not implemented for day {}", day))
+    })?;
+
+    let _span = info_span!("solve", year, day, part = 1, bytes = input.len()).entered();
+    let answer = panic::catch(|| solver(input));
+    debug!(ok = answer.is_ok(), "solved");
+    answer
 }
 
-/// Solves Part 2 for the given day
+/// Solves Part 2 for the given year and day
 ///
 /// # Arguments
-/// * `day` - The day number (1-25)
+/// * `year` - The event year
+/// * `day` - The day number (within the year's `calendar::valid_days`)
 /// * `input` - The input string for the day's challenge
 ///
 /// # Returns
 /// The solution as a String
 ///
 /// # Errors
-/// Returns an error if the day is not implemented or if solving fails
-pub fn solve_part2(day: u8, input: &str) -> Result<String> {
-    let registry = get_day_registry(day)
-        .ok_or_else(|| crate::utils::AocError::InvalidDay(day))?;
-    
-    registry
-        .part2
-        .ok_or_else(|| {
-            crate::utils::AocError::SolutionError(format!("Part 2 not implemented for day {}", day))
-        })?
-        (input)
-}
+/// Returns an error if the day is not part of the event, has no part 2,
+/// is not implemented, or if solving fails; a panicking solver is
+/// reported as `AocError::SolverPanic`
+pub fn solve_part2(year: u16, day: u8, input: &str) -> Result<String> {
+    calendar::check_part(year, day, 2)?;
+    let registry = get_registry(year, day)?;
 
+    let solver = registry.part2.ok_or_else(|| {
+        AocError::SolutionError(format!("Part 2 not implemented for day {}", day))
+    })?;
+
+    let _span = info_span!("solve", year, day, part = 2, bytes = input.len()).entered();
+    let answer = panic::catch(|| solver(input));
+    debug!(ok = answer.is_ok(), "solved");
+    answer
+}
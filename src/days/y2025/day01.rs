@@ -0,0 +1,223 @@
+use crate::utils::{Dir4, ParseDiagnostic, Result};
+
+/// Number of positions on the puzzle's dial (0-99)
+pub const DIAL_SIZE: i32 = 100;
+
+/// Where the dial points before the first rotation
+const DIAL_START: i32 = 50;
+
+/// Parses a rotation line: first character is direction (L or R), rest is distance
+///
+/// # Arguments
+/// * `input` - The full puzzle input (used to locate errors)
+/// * `line` - The trimmed rotation line, borrowed from `input`
+///
+/// # Returns
+/// The direction (`Dir4::Left` or `Dir4::Right`) and the distance
+///
+/// # Errors
+/// Returns a located parse error if the line is malformed, including a
+/// `U`/`D` direction (the dial only turns left or right)
+fn parse_rotation(input: &str, line: &str) -> Result<(Dir4, i32)> {
+    let mut chars = line.chars();
+    let direction_char = match chars.next() {
+        Some(c) if chars.next().is_some() => c,
+        _ => {
+            return Err(ParseDiagnostic::at_token(
+                input,
+                line,
+                "Rotation line too short",
+                "a direction followed by a distance",
+            )
+            .into())
+        }
+    };
+
+    let (direction_str, distance_str) = line.split_at(direction_char.len_utf8());
+    let direction = match Dir4::try_from(direction_char) {
+        Ok(dir @ (Dir4::Left | Dir4::Right)) => dir,
+        Ok(Dir4::Up | Dir4::Down) | Err(_) => {
+            return Err(ParseDiagnostic::at_token(
+                input,
+                direction_str,
+                format!("Invalid direction '{}' in rotation", direction_char),
+                "`L` or `R`",
+            )
+            .into())
+        }
+    };
+
+    let distance: i32 = distance_str.parse().map_err(|_| {
+        ParseDiagnostic::at_token(
+            input,
+            distance_str,
+            "Invalid distance in rotation",
+            "an integer",
+        )
+    })?;
+
+    Ok((direction, distance))
+}
+
+/// Solves Part 1 of Day 1
+///
+/// The dial starts at 50 and can be rotated left (L) or right (R) by a given distance.
+/// The dial is circular (0-99), so rotations wrap around.
+/// Returns the count of how many times the dial points at 0 after any rotation.
+///
+/// # Arguments
+/// * `input` - The input string containing rotation instructions (one per line)
+///
+/// # Returns
+/// The solution as a String (the count of times the dial points at 0)
+///
+/// # Errors
+/// Returns a `Result::Err` if there's an error parsing the input
+pub fn solve_part1(input: &str) -> Result<String> {
+    Ok(count_zero_stops(input, DIAL_SIZE)?.to_string())
+}
+
+/// One click of the dial: -1 for `Dir4::Left`, +1 for `Dir4::Right`
+fn click(direction: Dir4) -> i32 {
+    direction.delta().x as i32
+}
+
+/// Counts the rotations that leave a dial of `size` positions at 0
+///
+/// # Errors
+/// Returns a `Result::Err` if there's an error parsing the input
+pub fn count_zero_stops(input: &str, size: i32) -> Result<usize> {
+    let mut position: i32 = DIAL_START % size;
+    let mut count = 0;
+
+    for line in input.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let (direction, distance) = parse_rotation(input, line)?;
+
+        // Apply rotation, wrapping at 0 and 99
+        position = (position + click(direction) * distance).rem_euclid(size);
+
+        // Count if dial points at 0
+        if position == 0 {
+            count += 1;
+        }
+    }
+
+    Ok(count)
+}
+
+/// Solves Part 2 of Day 1
+///
+/// The dial starts at 50 and can be rotated left (L) or right (R) by a given distance.
+/// Counts EVERY time the dial points at 0 DURING a rotation (not just at the end).
+/// This includes all times the dial passes through 0 while rotating, including wrapping.
+///
+/// # Arguments
+/// * `input` - The input string containing rotation instructions (one per line)
+///
+/// # Returns
+/// The solution as a String (the total count of times the dial points at 0)
+///
+/// # Errors
+/// Returns a `Result::Err` if there's an error parsing the input
+pub fn solve_part2(input: &str) -> Result<String> {
+    Ok(count_zero_clicks(input, DIAL_SIZE)?.to_string())
+}
+
+/// Counts every click, during any rotation, that leaves a dial of `size`
+/// positions at 0
+///
+/// # Errors
+/// Returns a `Result::Err` if there's an error parsing the input
+pub fn count_zero_clicks(input: &str, size: i32) -> Result<i32> {
+    let mut position: i32 = DIAL_START % size;
+    let mut count = 0;
+
+    for line in input.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let (direction, distance) = parse_rotation(input, line)?;
+
+        // Count how many times we pass through 0 during the rotation
+        // We check the position after each of the 'distance' clicks
+        // (99->0->1->... to the right, 1->0->99->... to the left)
+        for _click in 1..=distance {
+            position = (position + click(direction)).rem_euclid(size);
+            if position == 0 {
+                count += 1;
+            }
+        }
+    }
+
+    Ok(count)
+}
+
+/// One rotation of the dial, as replayed by `trace`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DialStep {
+    /// 1-based line number of the rotation in the input
+    pub line: usize,
+    pub direction: Dir4,
+    pub distance: i32,
+    /// Where the dial points after the rotation (0 to size - 1)
+    pub position: i32,
+    /// How many clicks of the rotation left the dial at 0
+    pub zero_clicks: i32,
+}
+
+/// Replays the rotations from the starting position of 50
+///
+/// Part 1 counts the steps that end at 0; Part 2 sums `zero_clicks`.
+///
+/// # Arguments
+/// * `input` - The input string containing rotation instructions (one per line)
+/// * `size` - Number of positions on the dial (`DIAL_SIZE` for the puzzle)
+///
+/// # Returns
+/// One step per rotation, in input order
+///
+/// # Errors
+/// Returns a `Result::Err` if there's an error parsing the input
+pub fn trace(input: &str, size: i32) -> Result<Vec<DialStep>> {
+    let mut position: i32 = DIAL_START % size;
+    let mut steps = Vec::new();
+
+    for (idx, line) in input.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let (direction, distance) = parse_rotation(input, line)?;
+        let zero_clicks = if click(direction) > 0 {
+            // Click k lands on 0 when position + k is a multiple of size
+            (position + distance) / size
+        } else {
+            // Click k lands on 0 when k = position (mod size), first at k = position (or size from 0)
+            let first = if position == 0 { size } else { position };
+            if distance >= first {
+                (distance - first) / size + 1
+            } else {
+                0
+            }
+        };
+        position = (position + click(direction) * distance).rem_euclid(size);
+
+        steps.push(DialStep {
+            line: idx + 1,
+            direction,
+            distance,
+            position,
+            zero_clicks,
+        });
+    }
+
+    Ok(steps)
+}
@@ -1,4 +1,5 @@
-use crate::utils::{Result, AocError};
+use crate::utils::{checkpoint, ParseDiagnostic, Result};
+use tracing::trace;
 
 /// Checks if a product ID is invalid (made of a digit sequence repeated twice)
 ///
@@ -10,17 +11,17 @@ use crate::utils::{Result, AocError};
 fn is_invalid_id(id: u64) -> bool {
     let id_str = id.to_string();
     let len = id_str.len();
-    
+
     // Must have even length to be split into two equal halves
     if len % 2 != 0 {
         return false;
     }
-    
+
     // Split into two halves
     let half_len = len / 2;
     let first_half = &id_str[0..half_len];
     let second_half = &id_str[half_len..];
-    
+
     // Check if the two halves are identical
     first_half == second_half
 }
@@ -40,39 +41,50 @@ fn is_invalid_id(id: u64) -> bool {
 /// Returns a `Result::Err` if there's an error parsing the input
 pub fn solve_part1(input: &str) -> Result<String> {
     let mut total_sum: u64 = 0;
-    
+
     // Parse ranges from input (comma-separated)
     for range_str in input.trim().split(',') {
         let range_str = range_str.trim();
         if range_str.is_empty() {
             continue;
         }
-        
+
         // Parse range: "start-end"
         let parts: Vec<&str> = range_str.split('-').collect();
         if parts.len() != 2 {
-            return Err(AocError::ParseError(format!(
-                "Invalid range format: {}",
-                range_str
-            )));
+            return Err(ParseDiagnostic::at_token(
+                input,
+                range_str,
+                "Invalid range format",
+                "a range like `start-end`",
+            )
+            .into());
         }
-        
-        let start: u64 = parts[0]
-            .parse()
-            .map_err(|_| AocError::ParseError(format!("Invalid start value: {}", parts[0])))?;
-        
-        let end: u64 = parts[1]
-            .parse()
-            .map_err(|_| AocError::ParseError(format!("Invalid end value: {}", parts[1])))?;
-        
-        // Check all IDs in this range
+
+        let start: u64 = parts[0].parse().map_err(|_| {
+            ParseDiagnostic::at_token(
+                input,
+                parts[0],
+                "Invalid start value",
+                "an unsigned integer",
+            )
+        })?;
+
+        let end: u64 = parts[1].parse().map_err(|_| {
+            ParseDiagnostic::at_token(input, parts[1], "Invalid end value", "an unsigned integer")
+        })?;
+
+        trace!(start, end, "scanning range");
+
+        // Check all IDs in this range (a scan of huge ranges can be cancelled by --timeout)
         for id in start..=end {
+            checkpoint()?;
             if is_invalid_id(id) {
                 total_sum += id;
             }
         }
     }
-    
+
     Ok(total_sum.to_string())
 }
 
@@ -87,7 +99,7 @@ pub fn solve_part1(input: &str) -> Result<String> {
 fn is_invalid_id_part2(id: u64) -> bool {
     let id_str = id.to_string();
     let len = id_str.len();
-    
+
     // Try all possible part lengths (from 1 to len/2)
     // We need at least 2 repetitions, so part_len must be <= len/2
     for part_len in 1..=len / 2 {
@@ -95,19 +107,19 @@ fn is_invalid_id_part2(id: u64) -> bool {
         if len % part_len != 0 {
             continue;
         }
-        
+
         // Extract the first part
         let first_part = &id_str[0..part_len];
-        
+
         // Check if the entire string is made of repetitions of this part
         let num_repetitions = len / part_len;
         let reconstructed: String = first_part.repeat(num_repetitions);
-        
+
         if reconstructed == id_str {
             return true;
         }
     }
-    
+
     false
 }
 
@@ -126,39 +138,49 @@ fn is_invalid_id_part2(id: u64) -> bool {
 /// Returns a `Result::Err` if there's an error parsing the input
 pub fn solve_part2(input: &str) -> Result<String> {
     let mut total_sum: u64 = 0;
-    
+
     // Parse ranges from input (comma-separated)
     for range_str in input.trim().split(',') {
         let range_str = range_str.trim();
         if range_str.is_empty() {
             continue;
         }
-        
+
         // Parse range: "start-end"
         let parts: Vec<&str> = range_str.split('-').collect();
         if parts.len() != 2 {
-            return Err(AocError::ParseError(format!(
-                "Invalid range format: {}",
-                range_str
-            )));
+            return Err(ParseDiagnostic::at_token(
+                input,
+                range_str,
+                "Invalid range format",
+                "a range like `start-end`",
+            )
+            .into());
         }
-        
-        let start: u64 = parts[0]
-            .parse()
-            .map_err(|_| AocError::ParseError(format!("Invalid start value: {}", parts[0])))?;
-        
-        let end: u64 = parts[1]
-            .parse()
-            .map_err(|_| AocError::ParseError(format!("Invalid end value: {}", parts[1])))?;
-        
-        // Check all IDs in this range
+
+        let start: u64 = parts[0].parse().map_err(|_| {
+            ParseDiagnostic::at_token(
+                input,
+                parts[0],
+                "Invalid start value",
+                "an unsigned integer",
+            )
+        })?;
+
+        let end: u64 = parts[1].parse().map_err(|_| {
+            ParseDiagnostic::at_token(input, parts[1], "Invalid end value", "an unsigned integer")
+        })?;
+
+        trace!(start, end, "scanning range");
+
+        // Check all IDs in this range (a scan of huge ranges can be cancelled by --timeout)
         for id in start..=end {
+            checkpoint()?;
             if is_invalid_id_part2(id) {
                 total_sum += id;
             }
         }
     }
-    
+
     Ok(total_sum.to_string())
 }
-
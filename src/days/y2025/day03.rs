@@ -0,0 +1,178 @@
+use crate::utils::{AocError, ParseDiagnostic, Result};
+
+/// Solves Part 1 of Day 3
+///
+/// Each line is a bank of batteries with digit joltage ratings (0-9).
+/// We must turn on exactly 2 batteries per bank; the joltage produced
+/// is the two-digit number formed by those digits (in order).
+/// Find the maximum joltage from each bank and return the sum.
+///
+/// # Arguments
+/// * `input` - The input string containing battery banks (one per line)
+///
+/// # Returns
+/// The total output joltage as a String
+///
+/// # Errors
+/// Returns a `Result::Err` if there's an error parsing the input
+pub fn solve_part1(input: &str) -> Result<String> {
+    let mut total: u64 = 0;
+
+    for line in input.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let digits: Vec<u32> = line
+            .char_indices()
+            .map(|(idx, c)| {
+                c.to_digit(10).ok_or_else(|| {
+                    ParseDiagnostic::at_token(
+                        input,
+                        &line[idx..idx + c.len_utf8()],
+                        format!("Invalid digit '{}'", c),
+                        "a digit 0-9",
+                    )
+                    .into()
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        if digits.len() < 2 {
+            return Err(ParseDiagnostic::at_token(
+                input,
+                line,
+                "Bank must have at least 2 batteries",
+                "at least 2 digits",
+            )
+            .into());
+        }
+
+        // Find maximum two-digit number by selecting two positions i < j
+        let mut max_joltage: u32 = 0;
+
+        for i in 0..digits.len() - 1 {
+            for j in (i + 1)..digits.len() {
+                let joltage = digits[i] * 10 + digits[j];
+                if joltage > max_joltage {
+                    max_joltage = joltage;
+                }
+            }
+        }
+
+        total += max_joltage as u64;
+    }
+
+    Ok(total.to_string())
+}
+
+/// Batteries turned on per bank in Part 2
+pub const PART2_BATTERIES: usize = 12;
+
+/// Solves Part 2 of Day 3
+///
+/// Same as Part 1, but now we must turn on exactly 12 batteries per bank.
+/// The joltage is the 12-digit number formed by those digits (in order).
+/// Find the maximum joltage from each bank and return the sum.
+///
+/// # Arguments
+/// * `input` - The input string containing battery banks (one per line)
+///
+/// # Returns
+/// The total output joltage as a String
+///
+/// # Errors
+/// Returns a `Result::Err` if there's an error parsing the input
+pub fn solve_part2(input: &str) -> Result<String> {
+    Ok(total_joltage(input, PART2_BATTERIES)?.to_string())
+}
+
+/// Sums the maximum joltage of every bank when turning on `k` batteries each
+///
+/// # Arguments
+/// * `input` - The input string containing battery banks (one per line)
+/// * `k` - Number of batteries (digits) to select per bank
+///
+/// # Returns
+/// The total output joltage
+///
+/// # Errors
+/// Returns a `Result::Err` if a bank has an invalid digit or fewer than `k`
+/// batteries, or if the total doesn't fit in a `u128`
+pub fn total_joltage(input: &str, k: usize) -> Result<u128> {
+    let mut total: u128 = 0;
+
+    for line in input.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let digits: Vec<u32> = line
+            .char_indices()
+            .map(|(idx, c)| {
+                c.to_digit(10).ok_or_else(|| {
+                    ParseDiagnostic::at_token(
+                        input,
+                        &line[idx..idx + c.len_utf8()],
+                        format!("Invalid digit '{}'", c),
+                        "a digit 0-9",
+                    )
+                    .into()
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let n = digits.len();
+
+        if n < k {
+            return Err(ParseDiagnostic::at_token(
+                input,
+                line,
+                format!("Bank must have at least {} batteries, got {}", k, n),
+                format!("at least {} digits", k),
+            )
+            .into());
+        }
+
+        // Greedy selection: pick k digits to maximize the resulting number
+        // At each step, pick the largest digit that leaves enough remaining
+        let mut result_digits: Vec<u32> = Vec::with_capacity(k);
+        let mut start = 0;
+
+        for i in 0..k {
+            // Range of valid positions: [start, n - k + i]
+            // We need (k - i - 1) more digits after this one
+            let end = n - k + i;
+
+            // Find the maximum digit in range [start, end] (leftmost if ties)
+            let mut max_val = 0;
+            let mut max_pos = start;
+            for (offset, &digit) in digits[start..=end].iter().enumerate() {
+                if digit > max_val {
+                    max_val = digit;
+                    max_pos = start + offset;
+                }
+            }
+
+            result_digits.push(max_val);
+            start = max_pos + 1;
+        }
+
+        // Convert result_digits to a number
+        let overflow =
+            || AocError::SolutionError(format!("Joltage of {} digits overflows u128", k));
+        let mut joltage: u128 = 0;
+        for &d in &result_digits {
+            joltage = joltage
+                .checked_mul(10)
+                .and_then(|j| j.checked_add(d as u128))
+                .ok_or_else(overflow)?;
+        }
+
+        total = total.checked_add(joltage).ok_or_else(overflow)?;
+    }
+
+    Ok(total)
+}
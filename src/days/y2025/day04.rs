@@ -0,0 +1,175 @@
+use crate::utils::{checkpoint, AocError, Point2, Result};
+use tracing::{debug, instrument};
+
+/// A roll is accessible when fewer than this many of its neighbours are rolls
+pub const ACCESS_THRESHOLD: usize = 4;
+
+/// Solves Part 1 of Day 4
+///
+/// Count paper rolls (@) that have fewer than 4 adjacent rolls
+/// in the 8 neighboring positions (including diagonals).
+///
+/// # Arguments
+/// * `input` - The input string containing the grid
+///
+/// # Returns
+/// The count of accessible rolls as a String
+///
+/// # Errors
+/// Returns a `Result::Err` if there's an error parsing the input
+pub fn solve_part1(input: &str) -> Result<String> {
+    Ok(count_accessible(input, ACCESS_THRESHOLD)?.to_string())
+}
+
+/// Parses the grid of rolls (`@`) and empty cells
+///
+/// # Errors
+/// Returns a `Result::Err` if the grid is empty
+#[instrument(level = "debug", skip_all)]
+fn parse_grid(input: &str) -> Result<Vec<Vec<char>>> {
+    let grid: Vec<Vec<char>> = input
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .map(|line| line.chars().collect())
+        .collect();
+
+    if grid.is_empty() {
+        return Err(AocError::ParseError("Empty grid".to_string()));
+    }
+
+    debug!(rows = grid.len(), cols = grid[0].len(), "parsed grid");
+    Ok(grid)
+}
+
+/// Counts rolls with fewer than `threshold` adjacent rolls
+///
+/// # Errors
+/// Returns a `Result::Err` if the grid is empty
+pub fn count_accessible(input: &str, threshold: usize) -> Result<usize> {
+    let grid = parse_grid(input)?;
+
+    let rows = grid.len();
+    let cols = grid[0].len();
+
+    let mut accessible_count = 0;
+
+    for row in 0..rows {
+        for col in 0..cols {
+            // Only check positions with paper rolls
+            if grid[row][col] != '@' {
+                continue;
+            }
+
+            // Accessible if fewer than `threshold` adjacent rolls
+            if count_adjacent_rolls(&grid, row, col) < threshold {
+                accessible_count += 1;
+            }
+        }
+    }
+
+    Ok(accessible_count)
+}
+
+/// Marks every roll with whether it has fewer than `threshold` adjacent rolls
+///
+/// # Returns
+/// One row per grid line: `None` for an empty cell, `Some(accessible)` for a roll
+///
+/// # Errors
+/// Returns a `Result::Err` if the grid is empty
+pub fn accessibility_map(input: &str, threshold: usize) -> Result<Vec<Vec<Option<bool>>>> {
+    let grid = parse_grid(input)?;
+
+    Ok(grid
+        .iter()
+        .enumerate()
+        .map(|(row, line)| {
+            line.iter()
+                .enumerate()
+                .map(|(col, &cell)| {
+                    (cell == '@').then(|| count_adjacent_rolls(&grid, row, col) < threshold)
+                })
+                .collect()
+        })
+        .collect())
+}
+
+/// Counts adjacent rolls for a given position
+fn count_adjacent_rolls(grid: &[Vec<char>], row: usize, col: usize) -> usize {
+    let rows = grid.len();
+    let cols = grid[0].len();
+
+    // Check all 8 neighbours (orthogonal and diagonal), skipping out-of-bounds ones
+    Point2::from_grid(row, col)
+        .neighbors8()
+        .into_iter()
+        .filter_map(|p| p.to_grid(rows, cols))
+        .filter(|&(r, c)| grid[r][c] == '@')
+        .count()
+}
+
+/// Solves Part 2 of Day 4
+///
+/// Repeatedly remove accessible rolls (fewer than 4 adjacent)
+/// until no more can be removed. Count total rolls removed.
+///
+/// # Arguments
+/// * `input` - The input string containing the grid
+///
+/// # Returns
+/// The total count of removed rolls as a String
+///
+/// # Errors
+/// Returns a `Result::Err` if there's an error parsing the input
+pub fn solve_part2(input: &str) -> Result<String> {
+    Ok(count_removable(input, ACCESS_THRESHOLD)?.to_string())
+}
+
+/// Counts the rolls removed by repeatedly taking away every roll with
+/// fewer than `threshold` adjacent rolls
+///
+/// # Errors
+/// Returns a `Result::Err` if the grid is empty
+pub fn count_removable(input: &str, threshold: usize) -> Result<usize> {
+    let mut grid = parse_grid(input)?;
+
+    let rows = grid.len();
+    let cols = grid[0].len();
+
+    let mut total_removed = 0;
+
+    for round in 1.. {
+        checkpoint()?;
+
+        // Find all accessible rolls this round
+        let mut to_remove: Vec<(usize, usize)> = Vec::new();
+
+        for row in 0..rows {
+            for col in 0..cols {
+                if grid[row][col] == '@' {
+                    let adjacent = count_adjacent_rolls(&grid, row, col);
+                    if adjacent < threshold {
+                        to_remove.push((row, col));
+                    }
+                }
+            }
+        }
+
+        debug!(round, removed = to_remove.len(), "peeling round");
+
+        // Stop if no rolls can be removed
+        if to_remove.is_empty() {
+            break;
+        }
+
+        // Remove all accessible rolls
+        for (row, col) in &to_remove {
+            grid[*row][*col] = '.';
+        }
+
+        total_removed += to_remove.len();
+    }
+
+    Ok(total_removed)
+}
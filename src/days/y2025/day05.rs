@@ -0,0 +1,336 @@
+use crate::utils::{sections, AocError, ParseDiagnostic, RangeSet, Result, Section};
+use std::fmt;
+use tracing::{debug, instrument};
+
+/// An inclusive range of fresh ingredient IDs
+type IdRange = (u64, u64);
+
+/// Parses the input into ranges and ingredient IDs
+#[instrument(level = "debug", skip_all)]
+fn parse_input(input: &str) -> Result<(Vec<IdRange>, Vec<u64>)> {
+    let mut sections = sections(input).into_iter();
+
+    let ranges_section = sections
+        .next()
+        .ok_or_else(|| AocError::ParseError("Missing ranges section".to_string()))?;
+
+    let ids_section = sections
+        .next()
+        .ok_or_else(|| AocError::ParseError("Missing ingredient IDs section".to_string()))?;
+
+    let ranges = parse_ranges(input, &ranges_section)?;
+
+    // Parse ingredient IDs
+    let mut ids: Vec<u64> = Vec::new();
+    for (line_no, line) in ids_section.numbered_lines() {
+        let line = line.trim();
+
+        let id: u64 = line.parse().map_err(|_| {
+            let expected = "an unsigned integer";
+            ParseDiagnostic::at_line(
+                input,
+                line_no,
+                line,
+                line,
+                "Invalid ingredient ID",
+                expected,
+            )
+        })?;
+        ids.push(id);
+    }
+
+    debug!(ranges = ranges.len(), ids = ids.len(), "parsed input");
+    Ok((ranges, ids))
+}
+
+/// Parses the fresh ranges section (one `start-end` per line)
+fn parse_ranges(input: &str, section: &Section) -> Result<Vec<IdRange>> {
+    let mut ranges: Vec<IdRange> = Vec::new();
+    for (line_no, line) in section.numbered_lines() {
+        let line = line.trim();
+
+        let parts: Vec<&str> = line.split('-').collect();
+        if parts.len() != 2 {
+            return Err(ParseDiagnostic::at_line(
+                input,
+                line_no,
+                line,
+                line,
+                "Invalid range format",
+                "a range like `start-end`",
+            )
+            .into());
+        }
+
+        let start: u64 = parts[0].parse().map_err(|_| {
+            let expected = "an unsigned integer";
+            ParseDiagnostic::at_line(
+                input,
+                line_no,
+                line,
+                parts[0],
+                "Invalid range start",
+                expected,
+            )
+        })?;
+        let end: u64 = parts[1].parse().map_err(|_| {
+            let expected = "an unsigned integer";
+            ParseDiagnostic::at_line(
+                input,
+                line_no,
+                line,
+                parts[1],
+                "Invalid range end",
+                expected,
+            )
+        })?;
+
+        // A reversed range would silently count as empty (or underflow when sized)
+        if start > end {
+            return Err(ParseDiagnostic::at_line(
+                input,
+                line_no,
+                line,
+                line,
+                "Reversed range (start is greater than end)",
+                "a start no greater than the end",
+            )
+            .into());
+        }
+
+        ranges.push((start, end));
+    }
+    Ok(ranges)
+}
+
+/// Merges the fresh ranges into a `RangeSet`
+///
+/// # Errors
+/// Returns `AocError::ParseError` for a reversed range (`parse_input`
+/// already rejects those with a located diagnostic)
+fn merge_ranges(ranges: impl IntoIterator<Item = IdRange>) -> Result<RangeSet> {
+    let ranges: Vec<IdRange> = ranges.into_iter().collect();
+    let fresh = RangeSet::from_inclusive(ranges.iter().copied())?;
+    debug!(
+        ranges = ranges.len(),
+        merged = fresh.iter().count(),
+        "merged fresh ranges"
+    );
+    Ok(fresh)
+}
+
+/// Solves Part 1 of Day 5
+///
+/// Count how many available ingredient IDs are fresh
+/// (fall within at least one fresh range).
+///
+/// # Arguments
+/// * `input` - The input string containing ranges and ingredient IDs
+///
+/// # Returns
+/// The count of fresh ingredients as a String
+///
+/// # Errors
+/// Returns a `Result::Err` if there's an error parsing the input
+pub fn solve_part1(input: &str) -> Result<String> {
+    let (ranges, ids) = parse_input(input)?;
+    let fresh = merge_ranges(ranges)?;
+
+    let fresh_count = ids.iter().filter(|&&id| fresh.contains(id)).count();
+
+    Ok(fresh_count.to_string())
+}
+
+/// Solves Part 2 of Day 5
+///
+/// Count total unique ingredient IDs considered fresh by all ranges.
+/// Ranges can overlap, so they are merged into a `RangeSet` first.
+///
+/// # Arguments
+/// * `input` - The input string containing ranges and ingredient IDs
+///
+/// # Returns
+/// The total count of fresh IDs as a String
+///
+/// # Errors
+/// Returns a `Result::Err` if there's an error parsing the input
+pub fn solve_part2(input: &str) -> Result<String> {
+    let (ranges, _ids) = parse_input(input)?;
+
+    // The set merges overlapping ranges, so each ID is only counted once.
+    // The count is a u128: a single 0-18446744073709551615 range holds 2^64 IDs.
+    let total: u128 = merge_ranges(ranges)?.len();
+
+    Ok(total.to_string())
+}
+
+/// How a single ingredient ID relates to the fresh ranges
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IdStatus {
+    /// The ID is fresh; holds every input range that contains it
+    Fresh(Vec<(u64, u64)>),
+    /// The ID is spoiled; holds the closest input range and its distance, if any
+    Spoiled(Option<((u64, u64), u64)>),
+}
+
+impl fmt::Display for IdStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IdStatus::Fresh(ranges) => {
+                let ranges: Vec<String> = ranges
+                    .iter()
+                    .map(|(start, end)| format!("{}-{}", start, end))
+                    .collect();
+                write!(f, "fresh (in {})", ranges.join(", "))
+            }
+            IdStatus::Spoiled(Some(((start, end), distance))) => {
+                write!(
+                    f,
+                    "spoiled (nearest fresh range: {}-{}, {} away)",
+                    start, end, distance
+                )
+            }
+            IdStatus::Spoiled(None) => write!(f, "spoiled (no fresh ranges)"),
+        }
+    }
+}
+
+/// The fresh ranges from a Day 5 input, indexed for per-ID queries
+pub struct FreshDatabase {
+    /// Input ranges sorted by start
+    ranges: Vec<IdRange>,
+    /// `prefix_max_end[i]` is the index of the range with the largest end in `ranges[..=i]`
+    prefix_max_end: Vec<usize>,
+    /// Segment tree of the largest range end under each node, over the
+    /// leaves `ranges` (node 1 is the root, node `n` has children `2n` and `2n + 1`)
+    max_end: Vec<u64>,
+    /// Number of leaves in `max_end` (`ranges.len()` rounded up to a power of two)
+    leaves: usize,
+    /// Merged ranges for O(log n) freshness checks
+    fresh: RangeSet,
+}
+
+impl FreshDatabase {
+    /// Builds the database from the ranges section of a Day 5 input
+    ///
+    /// The ingredient IDs section is optional here, so a file holding only
+    /// ranges can be queried too.
+    ///
+    /// # Errors
+    /// Returns `AocError::ParseError` if the ranges are missing or malformed
+    pub fn parse(input: &str) -> Result<Self> {
+        let ranges_section = sections(input)
+            .into_iter()
+            .next()
+            .ok_or_else(|| AocError::ParseError("Missing ranges section".to_string()))?;
+        let mut ranges = parse_ranges(input, &ranges_section)?;
+        ranges.sort_unstable();
+
+        let mut prefix_max_end: Vec<usize> = Vec::with_capacity(ranges.len());
+        for (idx, &(_, end)) in ranges.iter().enumerate() {
+            let best = match prefix_max_end.last() {
+                Some(&prev) if ranges[prev].1 >= end => prev,
+                _ => idx,
+            };
+            prefix_max_end.push(best);
+        }
+
+        let leaves = ranges.len().next_power_of_two();
+        let mut max_end = vec![0; 2 * leaves];
+        for (idx, &(_, end)) in ranges.iter().enumerate() {
+            max_end[leaves + idx] = end;
+        }
+        for node in (1..leaves).rev() {
+            max_end[node] = max_end[2 * node].max(max_end[2 * node + 1]);
+        }
+
+        let fresh = merge_ranges(ranges.iter().copied())?;
+
+        Ok(FreshDatabase {
+            ranges,
+            prefix_max_end,
+            max_end,
+            leaves,
+            fresh,
+        })
+    }
+
+    /// Collects the ranges in `ranges[..split]` under `node` that end at or after `id`
+    ///
+    /// Subtrees whose largest end is below `id` are skipped, so this visits
+    /// O((k + 1) log n) nodes for k matching ranges.
+    fn collect_containing(
+        &self,
+        node: usize,
+        span: (usize, usize),
+        split: usize,
+        id: u64,
+        out: &mut Vec<IdRange>,
+    ) {
+        let (lo, hi) = span;
+        if lo >= split || self.max_end[node] < id {
+            return;
+        }
+        if hi - lo == 1 {
+            out.push(self.ranges[lo]);
+            return;
+        }
+        let mid = (lo + hi) / 2;
+        self.collect_containing(2 * node, (lo, mid), split, id, out);
+        self.collect_containing(2 * node + 1, (mid, hi), split, id, out);
+    }
+
+    /// Classifies an ID as fresh or spoiled
+    ///
+    /// Fresh IDs report every input range containing them. Spoiled IDs
+    /// report the nearest input range (ties go to the lower one).
+    pub fn classify(&self, id: u64) -> IdStatus {
+        // Ranges before `split` start at or below the ID
+        let split = self.ranges.partition_point(|&(start, _)| start <= id);
+
+        if self.fresh.contains(id) {
+            // Ranges starting at or below the ID contain it if they end at or after it
+            let mut containing = Vec::new();
+            self.collect_containing(1, (0, self.leaves), split, id, &mut containing);
+            return IdStatus::Fresh(containing);
+        }
+
+        // Not fresh, so every range starting at or below the ID ends before it
+        let below = split
+            .checked_sub(1)
+            .map(|idx| self.ranges[self.prefix_max_end[idx]])
+            .map(|range| (range, id - range.1));
+        let above = self.ranges.get(split).map(|&range| (range, range.0 - id));
+
+        let nearest = match (below, above) {
+            (Some(b), Some(a)) => Some(if a.1 < b.1 { a } else { b }),
+            (b, a) => b.or(a),
+        };
+        IdStatus::Spoiled(nearest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classify_reports_every_containing_range() {
+        let database = FreshDatabase::parse("3-5\n10-14\n16-20\n12-18\n1-30\n").unwrap();
+        match database.classify(13) {
+            IdStatus::Fresh(ranges) => assert_eq!(ranges, vec![(1, 30), (10, 14), (12, 18)]),
+            other => panic!("expected fresh, got {}", other),
+        }
+        match database.classify(31) {
+            IdStatus::Spoiled(nearest) => assert_eq!(nearest, Some(((1, 30), 1))),
+            other => panic!("expected spoiled, got {}", other),
+        }
+    }
+
+    #[test]
+    fn query_needs_only_the_ranges_section() {
+        let database = FreshDatabase::parse("3-5\n10-14\n").unwrap();
+        assert!(matches!(database.classify(4), IdStatus::Fresh(_)));
+        assert!(parse_input("3-5\n10-14\n").is_err());
+    }
+}
@@ -0,0 +1,44 @@
+//! Solvers for the 2025 event
+
+use super::DayRegistry;
+
+/// The event year these solvers belong to
+pub const YEAR: u16 = 2025;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+
+/// Day registry map - add new days here as they are implemented
+pub(super) fn get_day_registry(day: u8) -> Option<DayRegistry> {
+    match day {
+        1 => Some(DayRegistry {
+            title: Some("Dial"),
+            part1: Some(day01::solve_part1),
+            part2: Some(day01::solve_part2),
+        }),
+        2 => Some(DayRegistry {
+            title: Some("Product IDs"),
+            part1: Some(day02::solve_part1),
+            part2: Some(day02::solve_part2),
+        }),
+        3 => Some(DayRegistry {
+            title: Some("Lobby"),
+            part1: Some(day03::solve_part1),
+            part2: Some(day03::solve_part2),
+        }),
+        4 => Some(DayRegistry {
+            title: Some("Printing Department"),
+            part1: Some(day04::solve_part1),
+            part2: Some(day04::solve_part2),
+        }),
+        5 => Some(DayRegistry {
+            title: Some("Cafeteria"),
+            part1: Some(day05::solve_part1),
+            part2: Some(day05::solve_part2),
+        }),
+        _ => None,
+    }
+}
@@ -0,0 +1,232 @@
+use crate::utils::{AocError, Result};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+
+/// A record stored as one line of tab-separated fields
+pub trait TsvRecord: Sized {
+    /// What a record is, for error messages (e.g. "run history")
+    const KIND: &'static str;
+
+    /// The record's fields, unescaped
+    fn to_fields(&self) -> Vec<String>;
+
+    /// Rebuilds a record from the fields of one line (`None` if malformed)
+    fn from_fields(fields: &[&str]) -> Option<Self>;
+}
+
+/// A history file of `TsvRecord`s, oldest first
+///
+/// Fields are escaped (`\t`, `\n`, `\r` and `\\`) so any string can be stored.
+#[derive(Debug)]
+pub struct TsvHistory<T> {
+    path: PathBuf,
+    entries: Vec<T>,
+}
+
+impl<T: TsvRecord> TsvHistory<T> {
+    /// Loads a history file (an absent file is an empty history)
+    ///
+    /// # Errors
+    /// Returns `AocError::IoError` if the file exists but can't be read, or
+    /// `AocError::ParseError` if a line is malformed
+    pub fn open(path: PathBuf) -> Result<Self> {
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e.into()),
+        };
+
+        let entries = contents
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(idx, line)| {
+                let fields: Vec<String> = line.split('\t').map(unescape).collect();
+                let fields: Vec<&str> = fields.iter().map(String::as_str).collect();
+                T::from_fields(&fields).ok_or_else(|| {
+                    AocError::ParseError(format!(
+                        "Invalid {} entry on line {} of {}: {}",
+                        T::KIND,
+                        idx + 1,
+                        path.display(),
+                        line
+                    ))
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(TsvHistory { path, entries })
+    }
+
+    /// Every record, oldest first
+    pub fn entries(&self) -> &[T] {
+        &self.entries
+    }
+
+    /// Appends a record to the file
+    ///
+    /// # Errors
+    /// Returns `AocError::IoError` if the file can't be written
+    pub fn append(&mut self, record: T) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{}", to_line(&record))?;
+
+        self.entries.push(record);
+        Ok(())
+    }
+
+    /// Replaces the whole file with `entries`
+    ///
+    /// The new contents are written to a temporary file first, so an
+    /// interrupted write leaves the old history in place.
+    ///
+    /// # Errors
+    /// Returns `AocError::IoError` if the file can't be written
+    pub fn rewrite(&mut self, entries: Vec<T>) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let contents: String = entries.iter().map(|r| to_line(r) + "\n").collect();
+        let mut temp = self.path.clone().into_os_string();
+        temp.push(".tmp");
+        fs::write(&temp, contents)?;
+        fs::rename(&temp, &self.path)?;
+
+        self.entries = entries;
+        Ok(())
+    }
+}
+
+/// Joins a record's escaped fields with tabs
+fn to_line<T: TsvRecord>(record: &T) -> String {
+    record
+        .to_fields()
+        .iter()
+        .map(|field| escape(field))
+        .collect::<Vec<_>>()
+        .join("\t")
+}
+
+/// Escapes the characters that would break the line and field structure
+fn escape(field: &str) -> String {
+    let mut out = String::with_capacity(field.len());
+    for c in field.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '\t' => out.push_str("\\t"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Reverses `escape` (an unknown escape is kept as is)
+fn unescape(field: &str) -> String {
+    let mut out = String::with_capacity(field.len());
+    let mut chars = field.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('\\') => out.push('\\'),
+            Some('t') => out.push('\t'),
+            Some('n') => out.push('\n'),
+            Some('r') => out.push('\r'),
+            Some(other) => {
+                out.push('\\');
+                out.push(other);
+            }
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    struct Note(String, u32);
+
+    impl TsvRecord for Note {
+        const KIND: &'static str = "note";
+
+        fn to_fields(&self) -> Vec<String> {
+            vec![self.0.clone(), self.1.to_string()]
+        }
+
+        fn from_fields(fields: &[&str]) -> Option<Self> {
+            match fields {
+                [text, n] => Some(Note(text.to_string(), n.parse().ok()?)),
+                _ => None,
+            }
+        }
+    }
+
+    /// A scratch directory (removed on drop) and a history path inside it
+    fn scratch() -> (tempfile::TempDir, PathBuf) {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("history.tsv");
+        (dir, path)
+    }
+
+    #[test]
+    fn escapes_round_trip() {
+        for field in [
+            "plain",
+            "a\tb",
+            "two\nlines",
+            "back\\slash",
+            "\\t literal",
+            "end\\",
+        ] {
+            assert_eq!(unescape(&escape(field)), field);
+        }
+    }
+
+    #[test]
+    fn appends_and_reloads_awkward_fields() {
+        let (_dir, path) = scratch();
+        let mut history = TsvHistory::open(path.clone()).unwrap();
+        assert!(history.entries().is_empty());
+        history.append(Note("tab\there".to_string(), 1)).unwrap();
+        history.append(Note("line\nbreak".to_string(), 2)).unwrap();
+
+        let reloaded: TsvHistory<Note> = TsvHistory::open(path.clone()).unwrap();
+        assert_eq!(reloaded.entries(), history.entries());
+    }
+
+    #[test]
+    fn rewrite_replaces_the_file() {
+        let (_dir, path) = scratch();
+        let mut history = TsvHistory::open(path.clone()).unwrap();
+        history.append(Note("old".to_string(), 1)).unwrap();
+        history.rewrite(vec![Note("new".to_string(), 2)]).unwrap();
+
+        let reloaded: TsvHistory<Note> = TsvHistory::open(path.clone()).unwrap();
+        assert_eq!(reloaded.entries(), [Note("new".to_string(), 2)]);
+    }
+
+    #[test]
+    fn reports_malformed_lines() {
+        let (_dir, path) = scratch();
+        fs::write(&path, "ok\t1\nbroken\n").unwrap();
+        let err = TsvHistory::<Note>::open(path.clone()).unwrap_err();
+        assert!(
+            err.to_string().contains("Invalid note entry on line 2"),
+            "{err}"
+        );
+    }
+}
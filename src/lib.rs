@@ -1,6 +1,18 @@
+pub mod alloc;
+pub mod api;
+pub mod config;
 pub mod days;
+pub mod history;
+pub mod logging;
+pub mod repl;
+pub mod runner;
+pub mod runs;
+pub mod samples;
+pub mod scaffold;
+pub mod submit;
+pub mod tui;
 pub mod utils;
+pub mod watch;
 
 // Re-export commonly used types
-pub use utils::{AocError, Result, read_input};
-
+pub use utils::{read_input, AocError, Result};
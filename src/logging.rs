@@ -0,0 +1,85 @@
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io;
+use std::path::Path;
+use tracing::level_filters::LevelFilter;
+use tracing_chrome::{ChromeLayerBuilder, FlushGuard};
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{fmt, Layer};
+
+/// How log lines are written to stderr
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    /// Human-readable lines
+    Text,
+    /// One JSON object per line
+    Json,
+}
+
+/// The most detailed level logged for a `-v` count
+///
+/// Warnings only by default, then info (`-v`), debug (`-vv`) and trace (`-vvv`).
+pub fn level_for(verbosity: u8) -> LevelFilter {
+    match verbosity {
+        0 => LevelFilter::WARN,
+        1 => LevelFilter::INFO,
+        2 => LevelFilter::DEBUG,
+        _ => LevelFilter::TRACE,
+    }
+}
+
+/// Installs the global tracing subscriber
+///
+/// Log lines go to stderr, filtered by `verbosity`, so answers on stdout
+/// stay machine-readable. With `chrome_trace`, every span and event
+/// (whatever the verbosity) is also written to that file in the Chrome
+/// trace-event format, for `chrome://tracing` or https://ui.perfetto.dev.
+///
+/// # Arguments
+/// * `verbosity` - Number of `-v` flags (see `level_for`)
+/// * `format` - Text or JSON log lines
+/// * `chrome_trace` - Optional path for a trace-event file
+///
+/// # Returns
+/// A guard that finishes writing the trace file when dropped; keep it
+/// alive until the program ends
+///
+/// # Errors
+/// Returns the I/O error if the trace file can't be created
+pub fn init(
+    verbosity: u8,
+    format: LogFormat,
+    chrome_trace: Option<&Path>,
+) -> io::Result<Option<FlushGuard>> {
+    let level = level_for(verbosity);
+    let stderr = match format {
+        LogFormat::Text => fmt::layer()
+            .with_writer(io::stderr)
+            .with_filter(level)
+            .boxed(),
+        LogFormat::Json => fmt::layer()
+            .json()
+            .with_writer(io::stderr)
+            .with_filter(level)
+            .boxed(),
+    };
+
+    let (chrome, guard) = match chrome_trace {
+        Some(path) => {
+            let (layer, guard) = ChromeLayerBuilder::new()
+                .writer(File::create(path)?)
+                .include_args(true)
+                .build();
+            (Some(layer), Some(guard))
+        }
+        None => (None, None),
+    };
+
+    tracing_subscriber::registry()
+        .with(stderr)
+        .with(chrome)
+        .init();
+    Ok(guard)
+}
@@ -1,59 +1,535 @@
 use anyhow::{Context, Result as AnyhowResult};
-use clap::Parser;
+use aoc_2025::alloc::{self, AllocStats};
+use aoc_2025::api;
+use aoc_2025::config::{self, Config, OutputFormat};
 use aoc_2025::days;
+use aoc_2025::days::y2025::{self, day05::FreshDatabase};
+use aoc_2025::logging::{self, LogFormat};
+use aoc_2025::repl;
+use aoc_2025::runner::{self, PartJob};
+use aoc_2025::runs::{BenchHistory, BenchRecord, RunHistory, RunRecord};
+use aoc_2025::samples;
+use aoc_2025::scaffold;
+use aoc_2025::submit::{self, Submission, SubmissionHistory};
+use aoc_2025::tui;
 use aoc_2025::utils;
+use aoc_2025::utils::calendar;
+use aoc_2025::utils::crypto;
+use aoc_2025::utils::error::exit_code;
+use aoc_2025::watch::{self, FileWatcher};
+use clap::{CommandFactory, Parser, Subcommand};
+use serde::Serialize;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::{self as process, ExitCode};
+use std::time::{Duration, Instant};
 
-/// Advent of Code 2025 Solver
+/// Advent of Code Solver
 #[derive(Parser, Debug)]
 #[command(name = "aoc-2025")]
-#[command(about = "Solve Advent of Code 2025 challenges", long_about = None)]
+#[command(about = "Solve Advent of Code challenges", long_about = None)]
+#[command(subcommand_negates_reqs = true)]
 struct Args {
-    /// Day number (1-25)
-    #[arg(short, long, value_name = "DAY")]
-    day: u8,
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Event year (default: `year` in aoc.toml, then the current event)
+    #[arg(short, long, global = true, value_name = "YEAR", env = "AOC_YEAR")]
+    year: Option<u16>,
 
     /// Use sample input files instead of real input files
-    #[arg(short, long)]
+    #[arg(short, long, global = true)]
     sample: bool,
+
+    /// Use real input files even if aoc.toml sets `sample = true`
+    #[arg(long, global = true, conflicts_with = "sample")]
+    real: bool,
+
+    /// Directory holding the YYYY/ input directories (default: inputs)
+    #[arg(long, global = true, value_name = "DIR")]
+    input_dir: Option<PathBuf>,
+
+    /// Give up on a part after this long (e.g. 30s, 500ms, 2m; a plain number is seconds)
+    #[arg(short, long, global = true, value_name = "DURATION", value_parser = parse_timeout)]
+    timeout: Option<String>,
+
+    /// Log progress to stderr: -v for info, -vv for debug, -vvv for trace
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+
+    /// Format of the log lines on stderr (default: text)
+    #[arg(long, global = true, value_enum)]
+    log_format: Option<LogFormat>,
+
+    /// Format of the results of run, test and bench on stdout (default: text)
+    #[arg(long, global = true, value_enum)]
+    format: Option<OutputFormat>,
+
+    /// Also write every span and event to this file as a Chrome trace (chrome://tracing, Perfetto)
+    #[arg(long, global = true, value_name = "FILE")]
+    trace_file: Option<PathBuf>,
+
+    /// Without a subcommand, `aoc-2025 -d N [-s]` is shorthand for `aoc-2025 run -d N [-s]`
+    #[command(flatten, next_help_heading = "Run Options (without a subcommand)")]
+    run: RunArgs,
+}
+
+/// Options of `run`, also accepted without a subcommand
+#[derive(clap::Args, Debug, Clone)]
+struct RunArgs {
+    /// Day number (1-25 up to 2024, 1-12 from 2025)
+    #[arg(short, long, value_name = "DAY", required_unless_present = "all")]
+    day: Option<u8>,
+
+    /// Solve every implemented day of the year
+    #[arg(short, long, conflicts_with_all = ["day", "watch"])]
+    all: bool,
+
+    /// Worker threads for solving days and parts concurrently (with --all, default 1)
+    #[arg(short, long, value_name = "N", value_parser = clap::value_parser!(u16).range(1..), requires = "all", conflicts_with_all = ["day", "watch"])]
+    jobs: Option<u16>,
+
+    /// Re-run the day whenever its input files or solver source change
+    #[arg(short, long)]
+    watch: bool,
+}
+
+impl RunArgs {
+    /// Checks whether any option was given
+    fn is_used(&self) -> bool {
+        self.day.is_some() || self.all || self.jobs.is_some() || self.watch
+    }
+}
+
+#[derive(Subcommand, Debug, Clone)]
+enum Command {
+    /// Solve a day (or, with --all, every day) and print the answers
+    Run(RunArgs),
+
+    /// Time a day's parts (or every implemented part) over several runs
+    Bench {
+        /// Day number (default: every implemented day)
+        #[arg(short, long, value_name = "DAY")]
+        day: Option<u8>,
+
+        /// Runs per part
+        #[arg(short = 'n', long, value_name = "N", default_value_t = 10, value_parser = clap::value_parser!(u16).range(1..))]
+        runs: u16,
+
+        /// Print one JSON object per part instead of the table (same as `--format json`)
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Check answers against the recorded `.expected` files
+    Test {
+        /// Day number (default: every implemented day)
+        #[arg(short, long, value_name = "DAY")]
+        day: Option<u8>,
+
+        /// Worker threads for solving parts concurrently
+        #[arg(short, long, value_name = "N", default_value_t = 1, value_parser = clap::value_parser!(u16).range(1..))]
+        jobs: u16,
+    },
+
+    /// Classify ingredient IDs against the 2025 Day 5 fresh ranges
+    Query {
+        /// IDs to classify (read from stdin, whitespace-separated, if omitted)
+        #[arg(value_name = "ID")]
+        ids: Vec<u64>,
+    },
+
+    /// Show every day of the year: title, implemented parts, inputs, expected answers and last run time
+    List,
+
+    /// Full-screen dashboard: calendar by status, answers and timings, per-day visualizations
+    #[command(visible_alias = "dashboard")]
+    Viz {
+        /// Start on this day with its visualization open
+        #[arg(short, long, value_name = "DAY")]
+        day: Option<u8>,
+    },
+
+    /// Load a day's input once and explore it interactively (part1, part2, set, query, trace, reload)
+    Repl {
+        /// Day number (1-25 up to 2024, 1-12 from 2025)
+        #[arg(short, long, value_name = "DAY")]
+        day: u8,
+    },
+
+    /// Scaffold a new day of the solvers' year: solver module, registry entry and empty input files
+    New {
+        /// Day number (1-25 up to 2024, 1-12 from 2025)
+        #[arg(short, long, value_name = "DAY")]
+        day: u8,
+
+        /// Overwrite an existing solver module
+        #[arg(short, long)]
+        force: bool,
+    },
+
+    /// Download a day's real input (never re-downloads a cached input)
+    Fetch {
+        /// Day number (1-25 up to 2024, 1-12 from 2025)
+        #[arg(short, long, value_name = "DAY")]
+        day: u8,
+
+        /// Advent of Code server to download from
+        #[arg(long, value_name = "URL", env = "AOC_BASE_URL", default_value = api::DEFAULT_BASE_URL)]
+        base_url: String,
+    },
+
+    /// Solve a part on the real input and submit the answer
+    Submit {
+        /// Day number (1-25 up to 2024, 1-12 from 2025)
+        #[arg(short, long, value_name = "DAY")]
+        day: u8,
+
+        /// Part number (1 or 2)
+        #[arg(short, long, value_name = "PART", value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Advent of Code server to submit to
+        #[arg(long, value_name = "URL", env = "AOC_BASE_URL", default_value = api::DEFAULT_BASE_URL)]
+        base_url: String,
+    },
+
+    /// Extract sample inputs and expected answers from a puzzle page
+    Samples {
+        /// Day number (1-25 up to 2024, 1-12 from 2025)
+        #[arg(short, long, value_name = "DAY")]
+        day: u8,
+
+        /// Saved puzzle page to read (fetched from the server if omitted)
+        #[arg(long, value_name = "FILE")]
+        from: Option<PathBuf>,
+
+        /// Advent of Code server to fetch the page from
+        #[arg(long, value_name = "URL", env = "AOC_BASE_URL", default_value = api::DEFAULT_BASE_URL)]
+        base_url: String,
+
+        /// Overwrite existing sample and expected-answer files
+        #[arg(short, long)]
+        force: bool,
+    },
+
+    /// Encrypt real inputs to `inputs/YYYY/dayNNpP.txt.age` so they can be committed
+    Encrypt {
+        /// Only encrypt this day's inputs (default: every day)
+        #[arg(short, long, value_name = "DAY")]
+        day: Option<u8>,
+
+        /// Create a new key file first (at $AOC_INPUT_KEYFILE or .aoc/input.key)
+        #[arg(long)]
+        generate_key: bool,
+
+        /// Delete the plain-text inputs once encrypted
+        #[arg(long)]
+        remove_plaintext: bool,
+
+        /// Overwrite existing `.age` files
+        #[arg(short, long)]
+        force: bool,
+    },
+
+    /// Decrypt `inputs/YYYY/dayNNpP.txt.age` files back to plain text
+    Decrypt {
+        /// Only decrypt this day's inputs (default: every day)
+        #[arg(short, long, value_name = "DAY")]
+        day: Option<u8>,
+
+        /// Overwrite existing plain-text inputs
+        #[arg(short, long)]
+        force: bool,
+    },
+
+    /// Inspect the aoc.toml configuration
+    Config {
+        #[command(subcommand)]
+        action: ConfigAction,
+    },
+
+    /// Print a shell completion script (e.g. `aoc-2025 completions bash > ~/.local/share/bash-completion/completions/aoc-2025`)
+    Completions {
+        /// Shell to generate completions for
+        #[arg(value_enum)]
+        shell: clap_complete::Shell,
+    },
+
+    /// Print the man page, or write one page per subcommand to a directory
+    Man {
+        /// Directory to write `aoc-2025.1` and `aoc-2025-<subcommand>.1` into
+        #[arg(long, value_name = "DIR")]
+        out_dir: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand, Debug, Clone)]
+enum ConfigAction {
+    /// Print the effective configuration: aoc.toml files merged with flags and defaults
+    Show,
 }
 
-fn main() -> AnyhowResult<()> {
+fn main() -> ExitCode {
     let args = Args::parse();
+    // Global options may sit on either side of a subcommand, but the run
+    // shorthand's options make no sense next to one
+    if args.command.is_some() && args.run.is_used() {
+        Args::command()
+            .error(
+                clap::error::ErrorKind::ArgumentConflict,
+                "--day, --all, --jobs and --watch go after the subcommand, not before it",
+            )
+            .exit();
+    }
+    let root = Path::new(".");
+    let command = args
+        .command
+        .clone()
+        .unwrap_or_else(|| Command::Run(args.run.clone()));
+
+    // Completions and man pages don't depend on any setting, so they don't
+    // read aoc.toml at all and a broken one can't block them
+    let needs_config = !matches!(command, Command::Completions { .. } | Command::Man { .. });
+    let loaded = if needs_config {
+        Config::load(root)
+    } else {
+        Ok((Config::default(), Vec::new()))
+    };
+    let (file_config, loaded) = match loaded {
+        Ok(loaded) => loaded,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::from(e.exit_code());
+        }
+    };
+    let config = cli_config(&args).or(file_config).or(Config::defaults(root));
+    config.apply();
+    let year = config.year.unwrap_or(calendar::DEFAULT_YEAR);
+    let sample = config.sample.unwrap_or_default();
+    let timeout = config.timeout();
+    let log_format = config.log_format.unwrap_or(LogFormat::Text);
+    let json = config.format == Some(OutputFormat::Json);
+
+    // In watch mode each run is a child process, which writes the trace file itself
+    let watching = matches!(&command, Command::Run(run) if run.watch);
+    let trace_file = args.trace_file.as_deref().filter(|_| !watching);
+    let _trace_guard = match logging::init(args.verbose, log_format, trace_file) {
+        Ok(guard) => guard,
+        Err(e) => {
+            let path = trace_file.unwrap_or(Path::new("")).display();
+            eprintln!("Error: Could not create trace file {}: {}", path, e);
+            return ExitCode::from(exit_code::IO_ERROR);
+        }
+    };
+
+    let result = match command {
+        Command::Run(run) if run.all => run_all(
+            year,
+            sample,
+            usize::from(run.jobs.unwrap_or(1)),
+            timeout,
+            json,
+        ),
+        // `day` is required unless `all` is given
+        Command::Run(run) if run.watch => run_watch(run.day.unwrap_or_default(), &config, &args),
+        Command::Run(run) => run_day(year, run.day.unwrap_or_default(), sample, timeout, json),
+        Command::Bench {
+            day,
+            runs,
+            json: bench_json,
+        } => run_bench(
+            year,
+            day,
+            sample,
+            usize::from(runs),
+            timeout,
+            json || bench_json,
+        ),
+        Command::Test { day, jobs } => {
+            run_test(year, day, sample, usize::from(jobs), timeout, json)
+        }
+        Command::Query { ids } => run_query(year, ids, sample),
+        Command::List => run_list(year),
+        Command::Viz { day } => {
+            tui::run(year, sample, day, config.solver_params()).context("Dashboard failed")
+        }
+        Command::Repl { day } => {
+            repl::run(Path::new("."), year, day, sample, config.solver_params())
+                .context(format!("Failed to start the REPL for day {}", day))
+        }
+        Command::New { day, force } => run_new(year, day, force),
+        Command::Fetch { day, base_url } => run_fetch(year, day, &base_url, &config),
+        Command::Submit {
+            day,
+            part,
+            base_url,
+        } => run_submit(year, day, part, &base_url, &config),
+        Command::Samples {
+            day,
+            from,
+            base_url,
+            force,
+        } => run_samples(year, day, from.as_deref(), &base_url, force, &config),
+        Command::Encrypt {
+            day,
+            generate_key,
+            remove_plaintext,
+            force,
+        } => run_encrypt(year, day, generate_key, remove_plaintext, force),
+        Command::Decrypt { day, force } => run_decrypt(year, day, force),
+        Command::Config {
+            action: ConfigAction::Show,
+        } => run_config_show(&config, &loaded),
+        Command::Completions { shell } => {
+            clap_complete::generate(
+                shell,
+                &mut Args::command(),
+                "aoc-2025",
+                &mut std::io::stdout(),
+            );
+            Ok(())
+        }
+        Command::Man { out_dir } => run_man(out_dir.as_deref()),
+    };
+
+    match result {
+        Ok(()) => ExitCode::from(exit_code::SUCCESS),
+        Err(e) => {
+            eprintln!("Error: {:?}", e);
+            ExitCode::from(error_exit_code(&e))
+        }
+    }
+}
 
-    // Validate day number
-    if args.day < 1 || args.day > 25 {
-        anyhow::bail!("Day must be between 1 and 25, got: {}", args.day);
+/// Checks a `--timeout` value (see `config::parse_duration`), keeping it as
+/// written so it can be shown by `config show` and passed on in watch mode
+fn parse_timeout(value: &str) -> Result<String, String> {
+    config::parse_duration(value).map(|_| value.trim().to_string())
+}
+
+/// The settings given on the command line, which override `aoc.toml`
+fn cli_config(args: &Args) -> Config {
+    Config {
+        year: args.year,
+        sample: match (args.sample, args.real) {
+            (true, _) => Some(true),
+            (_, true) => Some(false),
+            _ => None,
+        },
+        input_dir: args.input_dir.clone(),
+        log_format: args.log_format,
+        format: args.format,
+        timeout: args.timeout.clone(),
+        ..Config::default()
     }
+}
+
+/// Maps an error to its documented exit code via the first `AocError` in its chain
+fn error_exit_code(e: &anyhow::Error) -> u8 {
+    e.chain()
+        .find_map(|cause| cause.downcast_ref::<utils::AocError>())
+        .map_or(exit_code::FAILURE, utils::AocError::exit_code)
+}
+
+/// Solves both parts of a day and prints the answers
+///
+/// With a `timeout`, a part still running when it passes fails with
+/// `AocError::Timeout` (see `runner::solve_input`). With `json`, each
+/// solved or failed part is printed as one `PartLine` instead.
+fn run_day(
+    year: u16,
+    day: u8,
+    sample: bool,
+    timeout: Option<Duration>,
+    json: bool,
+) -> AnyhowResult<()> {
+    // Validate year and day number, and that the puzzle is out
+    calendar::check_released(year, day)?;
 
     // Solve Part 1
-    println!("=== Day {} Part 1 ===", args.day);
-    let input_part1 = utils::read_input(args.day, 1, args.sample)
-        .context(format!("Failed to read input for day {} part 1", args.day))?;
-    match days::solve_part1(args.day, &input_part1) {
-        Ok(answer) => println!("Answer: {}\n", answer),
+    if !json {
+        println!("=== Day {} Part 1 ===", day);
+    }
+    let input_part1 = utils::read_input(year, day, 1, sample)
+        .context(format!("Failed to read input for day {} part 1", day))?;
+    let (answer, elapsed, alloc) = runner::solve_input(year, day, 1, &input_part1, timeout);
+    let job = PartJob {
+        year,
+        day,
+        part: 1,
+        sample,
+    };
+    match answer {
+        Ok(answer) => {
+            record_run(year, day, 1, sample, elapsed, alloc);
+            if json {
+                print_part_json(job, Ok(&answer), elapsed, alloc)?;
+            } else {
+                println!("Answer: {}", answer);
+                print_alloc(alloc);
+                println!();
+            }
+        }
         Err(e) => {
-            eprintln!("Error solving Part 1: {}\n", e);
+            let e = e.with_path(
+                utils::input_path(year, day, 1, sample)?
+                    .display()
+                    .to_string(),
+            );
+            report_error("Error solving Part 1", &e, &input_part1);
+            if json {
+                print_part_json(job, Err(&e), elapsed, alloc)?;
+            }
             return Err(anyhow::Error::from(e));
         }
     }
 
     // Solve Part 2
-    println!("=== Day {} Part 2 ===", args.day);
-    let input_part2 = match utils::read_input(args.day, 2, args.sample) {
+    if !json {
+        println!("=== Day {} Part 2 ===", day);
+    }
+    if calendar::parts_in_day(year, day)? == 1 {
+        if !json {
+            println!("Day {} is a single-part day; there is no Part 2.", day);
+        }
+        return Ok(());
+    }
+    let input_part2 = match utils::read_input(year, day, 2, sample) {
         Ok(input) => input,
         Err(utils::AocError::InputNotFound(_, _)) => {
-            println!("Unable to locate part 2 files.");
+            if !json {
+                println!("Unable to locate part 2 files.");
+            }
             return Ok(());
         }
         Err(e) => {
             return Err(anyhow::Error::from(e)
-                .context(format!("Failed to read input for day {} part 2", args.day)));
+                .context(format!("Failed to read input for day {} part 2", day)));
         }
     };
-    match days::solve_part2(args.day, &input_part2) {
-        Ok(answer) => println!("Answer: {}", answer),
+    let (answer, elapsed, alloc) = runner::solve_input(year, day, 2, &input_part2, timeout);
+    let job = PartJob { part: 2, ..job };
+    match answer {
+        Ok(answer) => {
+            record_run(year, day, 2, sample, elapsed, alloc);
+            if json {
+                print_part_json(job, Ok(&answer), elapsed, alloc)?;
+            } else {
+                println!("Answer: {}", answer);
+                print_alloc(alloc);
+            }
+        }
         Err(e) => {
-            eprintln!("Error solving Part 2: {}", e);
+            let e = e.with_path(
+                utils::input_path(year, day, 2, sample)?
+                    .display()
+                    .to_string(),
+            );
+            report_error("Error solving Part 2", &e, &input_part2);
+            if json {
+                print_part_json(job, Err(&e), elapsed, alloc)?;
+            }
             return Err(anyhow::Error::from(e));
         }
     }
@@ -61,3 +537,930 @@ fn main() -> AnyhowResult<()> {
     Ok(())
 }
 
+/// One part's `--format json` line from `run`
+#[derive(Serialize)]
+struct PartLine<'a> {
+    year: u16,
+    day: u8,
+    part: u8,
+    sample: bool,
+    answer: Option<&'a str>,
+    error: Option<String>,
+    elapsed_ns: u128,
+    /// `allocations`, `bytes` and `peak`, with the `alloc-stats` feature
+    #[serde(flatten)]
+    alloc: Option<AllocStats>,
+}
+
+/// Prints a solved or failed part as one JSON line
+fn print_part_json(
+    job: PartJob,
+    answer: Result<&str, &utils::AocError>,
+    elapsed: Duration,
+    alloc: Option<AllocStats>,
+) -> AnyhowResult<()> {
+    let line = PartLine {
+        year: job.year,
+        day: job.day,
+        part: job.part,
+        sample: job.sample,
+        answer: answer.ok(),
+        error: answer.err().map(ToString::to_string),
+        elapsed_ns: elapsed.as_nanos(),
+        alloc,
+    };
+    println!("{}", serde_json::to_string(&line)?);
+    Ok(())
+}
+
+/// Solves every implemented, released day of the year on `jobs` threads
+///
+/// Output is in day and part order whatever order the parts finish in. A
+/// failing (or panicking) part is reported and the rest still run; the
+/// first failure decides the exit code. With `json`, each part is printed
+/// as one `PartLine` and the summary is left out.
+fn run_all(
+    year: u16,
+    sample: bool,
+    jobs: usize,
+    timeout: Option<Duration>,
+    json: bool,
+) -> AnyhowResult<()> {
+    let queue = runner::implemented_jobs(year, None, sample)?;
+    let started = Instant::now();
+    let outcomes = runner::run_jobs(&queue, jobs, timeout);
+    let wall = started.elapsed();
+
+    let mut first_error = None;
+    let mut cpu = Duration::ZERO;
+    for outcome in outcomes {
+        let PartJob { day, part, .. } = outcome.job;
+        if !json {
+            println!("=== Day {} Part {} ===", day, part);
+        }
+        match outcome.answer {
+            Ok(answer) => {
+                record_run(year, day, part, sample, outcome.elapsed, outcome.alloc);
+                cpu += outcome.elapsed;
+                if json {
+                    print_part_json(outcome.job, Ok(&answer), outcome.elapsed, outcome.alloc)?;
+                } else {
+                    println!("Answer: {} ({:.2?})", answer, outcome.elapsed);
+                    print_alloc(outcome.alloc);
+                    println!();
+                }
+            }
+            // As with a single day, a missing part 2 input isn't a failure
+            Err(utils::AocError::InputNotFound(_, _)) if part == 2 => {
+                if !json {
+                    println!("Unable to locate part 2 files.\n");
+                }
+            }
+            Err(e) => {
+                report_part_error(&format!("Error solving Part {}", part), &e, outcome.job);
+                if json {
+                    print_part_json(outcome.job, Err(&e), outcome.elapsed, outcome.alloc)?;
+                }
+                first_error.get_or_insert(e);
+            }
+        }
+    }
+
+    if json {
+        return match first_error {
+            Some(e) => Err(e.into()),
+            None => Ok(()),
+        };
+    }
+    println!(
+        "Solved {} parts in {:.2?} ({:.2?} of solver time, {} thread{})",
+        queue.len(),
+        wall,
+        cpu,
+        jobs,
+        if jobs == 1 { "" } else { "s" }
+    );
+    match first_error {
+        Some(e) => Err(e.into()),
+        None => Ok(()),
+    }
+}
+
+/// One part's `bench --json` line
+#[derive(Serialize)]
+struct BenchLine<'a> {
+    year: u16,
+    day: u8,
+    part: u8,
+    runs: usize,
+    min_ns: Option<u128>,
+    median_ns: Option<u128>,
+    mean_ns: Option<u128>,
+    max_ns: Option<u128>,
+    answer: Option<&'a str>,
+    error: Option<String>,
+    /// `allocations`, `bytes` and `peak`, with the `alloc-stats` feature
+    #[serde(flatten)]
+    alloc: Option<AllocStats>,
+}
+
+/// Solves each implemented part `runs` times and prints its timings
+///
+/// The median run is recorded as the part's run time, and the whole spread
+/// is appended to the benchmark history. A failing part is
+/// reported and the rest are still benchmarked; the first failure decides
+/// the exit code. With the `alloc-stats` feature, the last run's allocations
+/// are shown too. With `json`, each part is printed as one JSON object per
+/// line instead of a table row.
+fn run_bench(
+    year: u16,
+    day: Option<u8>,
+    sample: bool,
+    runs: usize,
+    timeout: Option<Duration>,
+    json: bool,
+) -> AnyhowResult<()> {
+    let queue = runner::implemented_jobs(year, day, sample)?;
+
+    if !json {
+        println!("=== Bench {} ({} runs per part) ===", year, runs);
+        print!(
+            "{:>3}  {:>4}  {:>10}  {:>10}  {:>10}  {:>10}  ",
+            "Day", "Part", "Min", "Median", "Mean", "Max"
+        );
+        if alloc::ENABLED {
+            print!("{:>7}  {:>10}  {:>10}  ", "Allocs", "Allocated", "Peak");
+        }
+        println!("Answer");
+    }
+    let mut first_error = None;
+    for job in queue {
+        let bench = runner::bench_part(job, runs, timeout);
+        let PartJob { day, part, .. } = job;
+        if bench.answer.is_ok() {
+            record_run(year, day, part, sample, bench.median(), bench.alloc);
+            record_bench(BenchRecord {
+                timestamp: submit::now_timestamp(),
+                year,
+                day,
+                part,
+                sample,
+                runs: bench.times.len(),
+                min: bench.min(),
+                median: bench.median(),
+                mean: bench.mean(),
+                max: bench.max(),
+                alloc: bench.alloc,
+            });
+        }
+        let missing_part2 =
+            matches!(bench.answer, Err(utils::AocError::InputNotFound(_, _))) && part == 2;
+
+        if json {
+            let timed = bench.answer.is_ok();
+            let line = BenchLine {
+                year,
+                day,
+                part,
+                runs: bench.times.len(),
+                min_ns: timed.then(|| bench.min().as_nanos()),
+                median_ns: timed.then(|| bench.median().as_nanos()),
+                mean_ns: timed.then(|| bench.mean().as_nanos()),
+                max_ns: timed.then(|| bench.max().as_nanos()),
+                answer: bench.answer.as_deref().ok(),
+                error: bench.answer.as_ref().err().map(ToString::to_string),
+                alloc: bench.alloc,
+            };
+            println!("{}", serde_json::to_string(&line)?);
+        } else {
+            match bench.answer {
+                Ok(ref answer) => {
+                    print!(
+                        "{:>3}  {:>4}  {:>10}  {:>10}  {:>10}  {:>10}  ",
+                        day,
+                        part,
+                        format!("{:.2?}", bench.min()),
+                        format!("{:.2?}", bench.median()),
+                        format!("{:.2?}", bench.mean()),
+                        format!("{:.2?}", bench.max()),
+                    );
+                    if let Some(stats) = bench.alloc {
+                        print!(
+                            "{:>7}  {:>10}  {:>10}  ",
+                            stats.allocations,
+                            alloc::format_bytes(stats.bytes),
+                            alloc::format_bytes(stats.peak)
+                        );
+                    }
+                    println!("{}", answer);
+                }
+                Err(_) if missing_part2 => {
+                    println!("{:>3}  {:>4}  no part 2 input", day, part);
+                }
+                Err(ref e) => {
+                    println!(
+                        "{:>3}  {:>4}  failed after {} runs",
+                        day,
+                        part,
+                        bench.times.len()
+                    );
+                    report_part_error(
+                        &format!("Error benchmarking Day {} Part {}", day, part),
+                        e,
+                        job,
+                    );
+                }
+            }
+        }
+
+        if let Err(e) = bench.answer {
+            if !missing_part2 {
+                first_error.get_or_insert(e);
+            }
+        }
+    }
+
+    match first_error {
+        Some(e) => Err(e.into()),
+        None => Ok(()),
+    }
+}
+
+/// One part's `--format json` line from `test`
+#[derive(Serialize)]
+struct TestLine<'a> {
+    year: u16,
+    day: u8,
+    part: u8,
+    sample: bool,
+    /// `ok`, `failed`, `unchecked`, `no-input` or `error`
+    status: &'static str,
+    answer: Option<&'a str>,
+    expected: Option<&'a str>,
+    error: Option<String>,
+}
+
+/// Solves each implemented part and compares it with its `.expected` answer
+///
+/// Parts without an expected answer are listed but don't fail the test. A
+/// solver error decides the exit code over a wrong answer. With `json`, each
+/// part is printed as one `TestLine` instead.
+fn run_test(
+    year: u16,
+    day: Option<u8>,
+    sample: bool,
+    jobs: usize,
+    timeout: Option<Duration>,
+    json: bool,
+) -> AnyhowResult<()> {
+    let queue = runner::implemented_jobs(year, day, sample)?;
+    let outcomes = runner::run_jobs(&queue, jobs, timeout);
+
+    if !json {
+        println!(
+            "=== Test {} ({} input) ===",
+            year,
+            if sample { "sample" } else { "real" }
+        );
+    }
+    let (mut passed, mut unchecked) = (0, 0);
+    let mut mismatches = Vec::new();
+    let mut first_error = None;
+    for outcome in outcomes {
+        let PartJob { day, part, .. } = outcome.job;
+        let label = format!("Day {} Part {}", day, part);
+        let expected = utils::read_expected(year, day, part, sample);
+        let status = match &outcome.answer {
+            Ok(answer) => match &expected {
+                Some(expected) if expected == answer => {
+                    passed += 1;
+                    if !json {
+                        println!("{}: ok ({}, {:.2?})", label, answer, outcome.elapsed);
+                    }
+                    "ok"
+                }
+                Some(expected) => {
+                    if !json {
+                        println!("{}: FAILED (got {}, expected {})", label, answer, expected);
+                    }
+                    mismatches.push(format!("{}: got {}, expected {}", label, answer, expected));
+                    "failed"
+                }
+                None => {
+                    unchecked += 1;
+                    if !json {
+                        println!("{}: no expected answer (got {})", label, answer);
+                    }
+                    "unchecked"
+                }
+            },
+            Err(utils::AocError::InputNotFound(_, _)) if part == 2 => {
+                unchecked += 1;
+                if !json {
+                    println!("{}: no input", label);
+                }
+                "no-input"
+            }
+            Err(e) => {
+                if !json {
+                    println!("{}: ERROR", label);
+                }
+                report_part_error(&label, e, outcome.job);
+                "error"
+            }
+        };
+        if json {
+            let line = TestLine {
+                year,
+                day,
+                part,
+                sample,
+                status,
+                answer: outcome.answer.as_deref().ok(),
+                expected: expected.as_deref(),
+                error: outcome.answer.as_ref().err().map(ToString::to_string),
+            };
+            println!("{}", serde_json::to_string(&line)?);
+        }
+        if let (Err(e), "error") = (outcome.answer, status) {
+            first_error.get_or_insert(e);
+        }
+    }
+
+    let failed = mismatches.len() + usize::from(first_error.is_some());
+    if !json {
+        println!(
+            "\n{} passed, {} failed, {} unchecked",
+            passed, failed, unchecked
+        );
+    }
+    if let Some(e) = first_error {
+        return Err(e.into());
+    }
+    if !mismatches.is_empty() {
+        return Err(utils::AocError::WrongAnswer(mismatches.join("; ")).into());
+    }
+    Ok(())
+}
+
+/// Re-runs a day whenever its inputs (or, for a year with solvers in `days`,
+/// its solver source) change, and shows how the answers differ from the previous run
+///
+/// Each run is a child process of the current binary so that, after a
+/// source change triggers `cargo build`, the rebuilt solver is the one run.
+fn run_watch(day: u8, config: &Config, args: &Args) -> AnyhowResult<()> {
+    let year = config.year.unwrap_or(calendar::DEFAULT_YEAR);
+    let sample = config.sample.unwrap_or_default();
+    calendar::check_day(year, day)?;
+    let exe = std::env::current_exe().context("Failed to locate the running binary")?;
+    let source = days::source_dir(year).join(format!("day{:02}.rs", day));
+
+    let mut paths = Vec::new();
+    for part in 1..=calendar::parts_in_day(year, day)? {
+        let input = utils::input_path(year, day, part, sample)?;
+        paths.push(crypto::encrypted_path(&input));
+        paths.push(input);
+    }
+    if source.exists() {
+        paths.push(source.clone());
+    }
+    let mut watcher = FileWatcher::new(paths);
+
+    let mut previous: Vec<(u8, String)> = Vec::new();
+    let mut changed: Vec<PathBuf> = Vec::new();
+    loop {
+        if changed.contains(&source) {
+            println!("{} changed, rebuilding...", source.display());
+            let mut build = process::Command::new("cargo");
+            build.arg("build");
+            if exe.parent().is_some_and(|dir| dir.ends_with("release")) {
+                build.arg("--release");
+            }
+            if !build
+                .status()
+                .context("Failed to run cargo build")?
+                .success()
+            {
+                println!("Build failed, waiting for the next change...");
+                changed = watcher.wait_for_change();
+                continue;
+            }
+        }
+
+        let mut run = process::Command::new(&exe);
+        // Pass on the effective settings so the child doesn't re-resolve them differently
+        run.args(["--day", &day.to_string(), "--year", &year.to_string()]);
+        run.arg(if sample { "--sample" } else { "--real" });
+        if let Some(input_dir) = &config.input_dir {
+            run.arg("--input-dir").arg(input_dir);
+        }
+        if let Some(timeout) = &config.timeout {
+            run.args(["--timeout", timeout]);
+        }
+        if args.verbose > 0 {
+            run.arg(format!("-{}", "v".repeat(usize::from(args.verbose))));
+        }
+        if config.log_format == Some(LogFormat::Json) {
+            run.args(["--log-format", "json"]);
+        }
+        // The answers are read back from the child's text output
+        run.args(["--format", "text"]);
+        if let Some(trace_file) = &args.trace_file {
+            run.arg("--trace-file").arg(trace_file);
+        }
+        let output = run.output().context("Failed to run the solver")?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+
+        // Clear the screen and move the cursor home
+        print!("\x1b[2J\x1b[H");
+        println!(
+            "=== Watching Day {} ({} input, Ctrl-C to stop) ===\n",
+            day,
+            if sample { "sample" } else { "real" }
+        );
+        print!("{}", stdout);
+        eprint!("{}", String::from_utf8_lossy(&output.stderr));
+
+        let answers = watch::parse_answers(&stdout);
+        if !previous.is_empty() {
+            println!("\n=== Changes Since Last Run ===");
+            for part in 1..=2 {
+                let before = previous.iter().find(|(p, _)| *p == part).map(|(_, a)| a);
+                let after = answers.iter().find(|(p, _)| *p == part).map(|(_, a)| a);
+                match (before, after) {
+                    (Some(before), Some(after)) if before == after => {
+                        println!("Part {}: {} (unchanged)", part, after)
+                    }
+                    (Some(before), Some(after)) => {
+                        println!("Part {}: {} (was {})", part, after, before)
+                    }
+                    (None, Some(after)) => println!("Part {}: {} (new)", part, after),
+                    (Some(before), None) => println!("Part {}: no answer (was {})", part, before),
+                    (None, None) => {}
+                }
+            }
+        }
+        if !answers.is_empty() {
+            previous = answers;
+        }
+
+        println!("\nWatching:");
+        for path in watcher.paths().iter().filter(|path| path.exists()) {
+            println!("  {}", path.display());
+        }
+        changed = watcher.wait_for_change();
+    }
+}
+
+/// Records a successful run's time (and allocations, if counted) to
+/// `.aoc/runs.tsv`, warning if that fails
+fn record_run(
+    year: u16,
+    day: u8,
+    part: u8,
+    sample: bool,
+    elapsed: Duration,
+    alloc: Option<AllocStats>,
+) {
+    let recorded = RunHistory::load(Path::new(".")).and_then(|mut history| {
+        history.record(RunRecord {
+            timestamp: submit::now_timestamp(),
+            year,
+            day,
+            part,
+            sample,
+            elapsed,
+            alloc,
+        })
+    });
+    if let Err(e) = recorded {
+        eprintln!("Warning: could not record run time: {}", e);
+    }
+}
+
+/// Appends a successful benchmark to `.aoc/bench.tsv`, warning if that fails
+fn record_bench(bench: BenchRecord) {
+    let recorded = BenchHistory::load(Path::new(".")).and_then(|mut history| history.record(bench));
+    if let Err(e) = recorded {
+        eprintln!("Warning: could not record benchmark: {}", e);
+    }
+}
+
+/// Prints a solve's allocation statistics under its answer (with the `alloc-stats` feature)
+fn print_alloc(alloc: Option<AllocStats>) {
+    if let Some(alloc) = alloc {
+        println!("Memory: {}", alloc);
+    }
+}
+
+/// Prints an error to stderr, rendering located parse errors as a source
+/// snippet with a caret under the offending text
+fn report_error(context: &str, e: &utils::AocError, input: &str) {
+    match e {
+        utils::AocError::Diagnostic(diagnostic) => {
+            eprintln!("{}:\n{}\n", context, diagnostic.render(input))
+        }
+        other => eprintln!("{}: {}\n", context, other),
+    }
+}
+
+/// Like `report_error`, for a part whose input was read by the runner
+///
+/// The input is only read again when a parse error needs it for the snippet.
+fn report_part_error(context: &str, e: &utils::AocError, job: PartJob) {
+    let input = match e {
+        utils::AocError::Diagnostic(_) => {
+            utils::read_input(job.year, job.day, job.part, job.sample).unwrap_or_default()
+        }
+        _ => String::new(),
+    };
+    report_error(context, e, &input);
+}
+
+/// Reports whether each ID is fresh according to the Day 5 ranges
+fn run_query(year: u16, mut ids: Vec<u64>, sample: bool) -> AnyhowResult<()> {
+    if year != y2025::YEAR {
+        return Err(utils::AocError::SolutionError(format!(
+            "query works on Day 5 of {}, not {}",
+            y2025::YEAR,
+            year
+        ))
+        .into());
+    }
+    let input = utils::read_input(year, 5, 1, sample).context("Failed to read input for day 5")?;
+    let database = FreshDatabase::parse(&input).map_err(|e| {
+        let path = utils::input_path(year, 5, 1, sample).unwrap_or_default();
+        let e = e.with_path(path.display().to_string());
+        report_error("Error parsing Day 5 ranges", &e, &input);
+        e
+    })?;
+
+    if ids.is_empty() {
+        let mut stdin = String::new();
+        std::io::stdin()
+            .read_to_string(&mut stdin)
+            .context("Failed to read IDs from stdin")?;
+        ids = stdin
+            .split_whitespace()
+            .map(|token| {
+                token.parse().map_err(|_| {
+                    utils::AocError::ParseError(format!("Invalid ingredient ID: {}", token)).into()
+                })
+            })
+            .collect::<AnyhowResult<_>>()?;
+    }
+
+    println!("=== Day 5 Query ===");
+    for id in ids {
+        println!("{}: {}", id, database.classify(id));
+    }
+
+    Ok(())
+}
+
+/// Prints a calendar table with the implementation and input status of every day
+fn run_list(year: u16) -> AnyhowResult<()> {
+    let days_in_year = calendar::valid_days(year).ok_or(utils::AocError::InvalidYear(year))?;
+    let runs = RunHistory::load(Path::new("."))?;
+
+    // Marks each of a day's parts with its number if `present`, `-` if not
+    let per_part = |parts: u8, present: &dyn Fn(u8) -> bool| -> String {
+        (1..=parts)
+            .map(|part| {
+                if present(part) {
+                    part.to_string()
+                } else {
+                    "-".to_string()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    let exists = |path: utils::Result<PathBuf>| {
+        path.is_ok_and(|path| path.exists() || crypto::encrypted_path(&path).exists())
+    };
+
+    println!("=== {} Calendar ===", year);
+    println!(
+        "{:>3}  {:<30}  {:<3}  {:<3}  {:<4}  {:<6}  {:<7}  {:<10}  Last run",
+        "Day", "Title", "P1", "P2", "Real", "Sample", "Answers", "Sample ans"
+    );
+    for day in days_in_year {
+        let info = days::day_info(year, day)?;
+        let implemented = |done: bool| if done { "yes" } else { "-" };
+        let title = match info.title {
+            Some(title) => title.to_string(),
+            None if calendar::check_released(year, day).is_err() => {
+                format!("(unlocks {})", calendar::unlock_label(year, day))
+            }
+            None => "-".to_string(),
+        };
+        let last_run = (1..=info.parts)
+            .map(|part| match runs.latest(year, day, part, false) {
+                Some(run) => format!("{:.2?}", run.elapsed),
+                None => "-".to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join(" / ");
+
+        println!(
+            "{:>3}  {:<30}  {:<3}  {:<3}  {:<4}  {:<6}  {:<7}  {:<10}  {}",
+            day,
+            title,
+            implemented(info.part1_implemented),
+            if info.parts == 1 {
+                "n/a"
+            } else {
+                implemented(info.part2_implemented)
+            },
+            per_part(info.parts, &|part| exists(utils::input_path(
+                year, day, part, false
+            ))),
+            per_part(info.parts, &|part| exists(utils::input_path(
+                year, day, part, true
+            ))),
+            per_part(info.parts, &|part| exists(utils::expected_path(
+                year, day, part, false
+            ))),
+            per_part(info.parts, &|part| exists(utils::expected_path(
+                year, day, part, true
+            ))),
+            last_run
+        );
+    }
+
+    Ok(())
+}
+
+/// Generates the files for a new day and lists what was written
+fn run_new(year: u16, day: u8, force: bool) -> AnyhowResult<()> {
+    let report = scaffold::scaffold_day(Path::new("."), year, day, force)
+        .context(format!("Failed to scaffold day {}", day))?;
+
+    println!("=== Scaffolded Day {} ===", day);
+    for path in &report.written {
+        println!("Wrote {}", path.display());
+    }
+    for path in &report.skipped {
+        println!("Kept existing {}", path.display());
+    }
+
+    Ok(())
+}
+
+/// Downloads (or reuses) a day's real input
+fn run_fetch(year: u16, day: u8, base_url: &str, config: &Config) -> AnyhowResult<()> {
+    let outcome = api::fetch_input(
+        Path::new("."),
+        year,
+        day,
+        base_url,
+        config.session_file.as_deref(),
+    )
+    .context(format!("Failed to fetch input for day {}", day))?;
+
+    println!("=== Fetch {} Day {} ===", year, day);
+    let written = match outcome {
+        api::FetchOutcome::Cached(written) => {
+            println!("Input already cached, nothing downloaded");
+            written
+        }
+        api::FetchOutcome::Downloaded(written) => {
+            println!("Downloaded input");
+            written
+        }
+    };
+    for path in written {
+        println!("Wrote {}", path.display());
+    }
+
+    Ok(())
+}
+
+/// Solves a part on the real input, submits the answer and records the verdict
+fn run_submit(year: u16, day: u8, part: u8, base_url: &str, config: &Config) -> AnyhowResult<()> {
+    let root = Path::new(".");
+    calendar::check_part(year, day, part)?;
+    calendar::check_released(year, day)?;
+    let input = utils::read_input(year, day, part, false).context(format!(
+        "Failed to read input for day {} part {}",
+        day, part
+    ))?;
+    let answer = match part {
+        1 => days::solve_part1(year, day, &input),
+        _ => days::solve_part2(year, day, &input),
+    }?;
+
+    println!("=== Submit {} Day {} Part {} ===", year, day, part);
+    println!("Answer: {}", answer);
+
+    let mut history = match &config.answers {
+        Some(path) => SubmissionHistory::open(path.clone())?,
+        None => SubmissionHistory::load(root)?,
+    };
+    let accepted = history
+        .attempts(year, day, part)
+        .any(|a| a.verdict == submit::Verdict::Correct && a.answer == answer);
+    if accepted {
+        println!("Verdict: correct (already accepted, not resubmitting)");
+        return Ok(());
+    }
+    if let Some(reason) = history.refusal(year, day, part, &answer) {
+        return Err(utils::AocError::WrongAnswer(format!("not submitting: {}", reason)).into());
+    }
+
+    let session = api::load_session(root, config.session_file.as_deref())?;
+    let client = api::AocClient::new(base_url, session, root);
+    let verdict = client.submit_answer(year, day, part, &answer)?;
+    history.record(Submission {
+        timestamp: submit::now_timestamp(),
+        year,
+        day,
+        part,
+        answer: answer.clone(),
+        verdict: verdict.clone(),
+    })?;
+
+    println!("Verdict: {}", verdict);
+    match verdict {
+        submit::Verdict::Correct | submit::Verdict::AlreadySolved => Ok(()),
+        submit::Verdict::RateLimited(_) => {
+            Err(utils::AocError::RequestError(verdict.to_string()).into())
+        }
+        _ => Err(utils::AocError::WrongAnswer(format!("{} is {}", answer, verdict)).into()),
+    }
+}
+
+/// Writes sample inputs and expected answers extracted from a puzzle page
+fn run_samples(
+    year: u16,
+    day: u8,
+    from: Option<&Path>,
+    base_url: &str,
+    force: bool,
+    config: &Config,
+) -> AnyhowResult<()> {
+    let root = Path::new(".");
+    let html = match from {
+        Some(path) => std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read puzzle page {}", path.display()))?,
+        None => {
+            calendar::check_released(year, day)?;
+            let session = api::load_session(root, config.session_file.as_deref())?;
+            let client = api::AocClient::new(base_url, session, root);
+            client.get_puzzle_page(year, day)?
+        }
+    };
+
+    let found = samples::extract_samples(&html);
+    if found.is_empty() {
+        anyhow::bail!("No sample input found in the puzzle page for day {}", day);
+    }
+
+    println!("=== Samples {} Day {} ===", year, day);
+    for sample in &found {
+        match &sample.expected {
+            Some(expected) => println!("Part {}: expected answer {}", sample.part, expected),
+            None => println!("Part {}: no expected answer found", sample.part),
+        }
+    }
+
+    let report = samples::write_samples(root, year, day, &found, force)?;
+    for path in &report.written {
+        println!("Wrote {}", path.display());
+    }
+    for path in &report.skipped {
+        println!(
+            "Kept existing {} (use --force to overwrite)",
+            path.display()
+        );
+    }
+
+    Ok(())
+}
+
+/// Real input paths for one day, or for every day of the year when `day` is `None`
+fn real_input_paths(year: u16, day: Option<u8>) -> AnyhowResult<Vec<PathBuf>> {
+    let days = match day {
+        Some(day) => day..=day,
+        None => calendar::valid_days(year).ok_or(utils::AocError::InvalidYear(year))?,
+    };
+
+    let mut paths = Vec::new();
+    for day in days {
+        for part in 1..=calendar::parts_in_day(year, day)? {
+            paths.push(utils::input_path(year, day, part, false)?);
+        }
+    }
+    Ok(paths)
+}
+
+/// Encrypts plain-text real inputs next to the originals
+fn run_encrypt(
+    year: u16,
+    day: Option<u8>,
+    generate_key: bool,
+    remove_plaintext: bool,
+    force: bool,
+) -> AnyhowResult<()> {
+    println!("=== Encrypt Inputs ===");
+    if generate_key {
+        let keyfile = std::env::var(crypto::KEYFILE_ENV)
+            .unwrap_or_else(|_| crypto::DEFAULT_KEYFILE.to_string());
+        let recipient = crypto::generate_keyfile(Path::new(&keyfile))
+            .context("Failed to generate input key")?;
+        println!("Created {} (public key {})", keyfile, recipient);
+        println!("Share this file privately with the team; never commit it.");
+    }
+
+    let identity = crypto::load_identity()?;
+    for path in real_input_paths(year, day)? {
+        if !path.exists() {
+            continue;
+        }
+
+        let encrypted = crypto::encrypted_path(&path);
+        if encrypted.exists() && !force {
+            println!(
+                "Kept existing {} (use --force to overwrite)",
+                encrypted.display()
+            );
+            continue;
+        }
+
+        let plaintext = std::fs::read_to_string(&path)?;
+        std::fs::write(&encrypted, crypto::encrypt(&identity, &plaintext)?)?;
+        println!("Wrote {}", encrypted.display());
+
+        if remove_plaintext {
+            std::fs::remove_file(&path)?;
+            println!("Removed {}", path.display());
+        }
+    }
+
+    Ok(())
+}
+
+/// Decrypts encrypted real inputs back to the plain-text names
+fn run_decrypt(year: u16, day: Option<u8>, force: bool) -> AnyhowResult<()> {
+    println!("=== Decrypt Inputs ===");
+    let identity = crypto::load_identity()?;
+    for path in real_input_paths(year, day)? {
+        let encrypted = crypto::encrypted_path(&path);
+        if !encrypted.exists() {
+            continue;
+        }
+        if path.exists() && !force {
+            println!(
+                "Kept existing {} (use --force to overwrite)",
+                path.display()
+            );
+            continue;
+        }
+
+        std::fs::write(&path, crypto::decrypt_file(&encrypted, &identity)?)?;
+        println!("Wrote {}", path.display());
+    }
+
+    Ok(())
+}
+
+/// Prints the effective configuration as `aoc.toml` text, after the files it was read from
+fn run_config_show(config: &Config, loaded: &[PathBuf]) -> AnyhowResult<()> {
+    if loaded.is_empty() {
+        println!(
+            "# No {} found; showing built-in defaults",
+            config::CONFIG_FILE
+        );
+    }
+    for path in loaded {
+        println!("# Loaded {}", path.display());
+    }
+    if config.timeout.is_none() {
+        println!("# timeout: none");
+    }
+    if config.session_file.is_none() {
+        println!(
+            "# session_file: none (session from {}, .aoc/session or ~/.config/aoc/session)",
+            api::SESSION_ENV
+        );
+    }
+    print!("{}", config.to_toml()?);
+    Ok(())
+}
+
+/// Prints the man page, or writes it and one page per subcommand to `out_dir`
+fn run_man(out_dir: Option<&Path>) -> AnyhowResult<()> {
+    let command = Args::command();
+    match out_dir {
+        Some(dir) => {
+            std::fs::create_dir_all(dir)
+                .with_context(|| format!("Failed to create {}", dir.display()))?;
+            clap_mangen::generate_to(command, dir)
+                .with_context(|| format!("Failed to write man pages to {}", dir.display()))?;
+            println!("Wrote man pages to {}", dir.display());
+        }
+        None => clap_mangen::Man::new(command).render(&mut std::io::stdout())?,
+    }
+    Ok(())
+}
@@ -0,0 +1,508 @@
+use crate::api::STATE_DIR;
+use crate::days::y2025::{self, day01, day03, day04, day05::FreshDatabase};
+use crate::days::{self, SolverParams};
+use crate::utils::{panic, read_input, AocError, Dir4, Result};
+use rustyline::completion::Completer;
+use rustyline::error::ReadlineError;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::history::DefaultHistory;
+use rustyline::validate::Validator;
+use rustyline::{Context, Editor, Helper};
+use std::fmt::Write;
+use std::path::Path;
+use std::time::Instant;
+
+/// Commands available for every day
+const COMMON_COMMANDS: &[&str] = &[
+    "part1", "part2", "show", "reload", "sample", "real", "help", "quit",
+];
+
+/// A day's input held in memory for interactive exploration
+///
+/// The inputs are read once (and again on `reload`); day-specific state
+/// such as the Day 5 range index is built at the same time, so commands
+/// run against it without re-reading or recompiling anything.
+pub struct ReplSession {
+    year: u16,
+    day: u8,
+    sample: bool,
+    input_part1: String,
+    /// `None` if there is no part 2 input (or the day has one part)
+    input_part2: Option<String>,
+    /// Day 5 only: the fresh ranges, indexed for `query`
+    database: Option<FreshDatabase>,
+    /// Day 1: positions on the dial
+    dial_size: i32,
+    /// Day 3: batteries turned on per bank by `part2`
+    k: usize,
+    /// Day 4: a roll is accessible with fewer than this many neighbouring rolls
+    threshold: usize,
+}
+
+impl ReplSession {
+    /// Reads (and, where a day has an index, parses) a day's inputs
+    ///
+    /// # Errors
+    /// Returns the errors of `read_input` for part 1, or a parse error for
+    /// a malformed Day 5 input
+    pub fn load(year: u16, day: u8, sample: bool, params: SolverParams) -> Result<Self> {
+        let mut session = ReplSession {
+            year,
+            day,
+            sample,
+            input_part1: String::new(),
+            input_part2: None,
+            database: None,
+            dial_size: params.dial_size,
+            k: params.k,
+            threshold: params.threshold,
+        };
+        session.reload()?;
+        Ok(session)
+    }
+
+    /// Re-reads the inputs from disk, keeping the current parameters
+    ///
+    /// # Errors
+    /// Same as `load`
+    pub fn reload(&mut self) -> Result<()> {
+        self.input_part1 = read_input(self.year, self.day, 1, self.sample)?;
+        self.input_part2 = match read_input(self.year, self.day, 2, self.sample) {
+            Ok(input) => Some(input),
+            Err(AocError::InputNotFound(_, _) | AocError::SinglePartDay(_, _)) => None,
+            Err(e) => return Err(e),
+        };
+        self.database = match self.puzzle_day() {
+            Some(5) => Some(FreshDatabase::parse(&self.input_part1)?),
+            _ => None,
+        };
+        Ok(())
+    }
+
+    /// The day, if it's one of the 2025 puzzles with day-specific commands
+    fn puzzle_day(&self) -> Option<u8> {
+        (self.year == y2025::YEAR).then_some(self.day)
+    }
+
+    /// Every command this session understands
+    pub fn commands(&self) -> Vec<&'static str> {
+        let mut commands = COMMON_COMMANDS.to_vec();
+        match self.puzzle_day() {
+            Some(1) => commands.extend(["trace", "set"]),
+            Some(3 | 4) => commands.push("set"),
+            Some(5) => commands.push("query"),
+            _ => {}
+        }
+        commands
+    }
+
+    /// Parameters `set` accepts for this day
+    pub fn parameters(&self) -> &'static [&'static str] {
+        match self.puzzle_day() {
+            Some(1) => &["dial_size"],
+            Some(3) => &["k"],
+            Some(4) => &["threshold"],
+            _ => &[],
+        }
+    }
+
+    /// Runs one command line
+    ///
+    /// # Returns
+    /// The text to print, or `None` when the session should end
+    ///
+    /// # Errors
+    /// Returns an error for an unknown command or bad arguments, or
+    /// whatever the solver returns
+    pub fn execute(&mut self, line: &str) -> Result<Option<String>> {
+        let mut words = line.split_whitespace();
+        let Some(command) = words.next() else {
+            return Ok(Some(String::new()));
+        };
+        let args: Vec<&str> = words.collect();
+
+        let output = match command {
+            "part1" => self.solve(1)?,
+            "part2" => self.solve(2)?,
+            "show" => self.show(),
+            "reload" => {
+                self.reload()?;
+                "Reloaded the input files".to_string()
+            }
+            "sample" => self.switch_input(true)?,
+            "real" => self.switch_input(false)?,
+            "help" => self.help(),
+            "quit" | "exit" => return Ok(None),
+            "set" if !self.parameters().is_empty() => self.set(&args)?,
+            "trace" if self.puzzle_day() == Some(1) => self.trace(&args)?,
+            "query" if self.database.is_some() => self.query(&args)?,
+            _ => {
+                return Err(AocError::ParseError(format!(
+                    "Unknown command '{}' (try `help`)",
+                    command
+                )))
+            }
+        };
+        Ok(Some(output))
+    }
+
+    /// Solves a part with the current parameters and reports the time taken
+    fn solve(&self, part: u8) -> Result<String> {
+        let input = match part {
+            1 => &self.input_part1,
+            _ => self.input_part2.as_ref().ok_or_else(|| {
+                AocError::InputNotFound(self.day, "no part 2 input was loaded".to_string())
+            })?,
+        };
+
+        let started = Instant::now();
+        // A panic (e.g. from an unusual `set` value) is reported, not fatal
+        let answer = panic::catch(|| match (self.puzzle_day(), part) {
+            (Some(1), 1) => Ok(day01::count_zero_stops(input, self.dial_size)?.to_string()),
+            (Some(1), 2) => Ok(day01::count_zero_clicks(input, self.dial_size)?.to_string()),
+            (Some(3), 2) => Ok(day03::total_joltage(input, self.k)?.to_string()),
+            (Some(4), 1) => Ok(day04::count_accessible(input, self.threshold)?.to_string()),
+            (Some(4), 2) => Ok(day04::count_removable(input, self.threshold)?.to_string()),
+            (_, 1) => days::solve_part1(self.year, self.day, input),
+            _ => days::solve_part2(self.year, self.day, input),
+        })?;
+        Ok(format!(
+            "Part {}: {} ({:.2?})",
+            part,
+            answer,
+            started.elapsed()
+        ))
+    }
+
+    /// `sample` / `real`: loads the other kind of input, keeping the parameters
+    ///
+    /// If the new input can't be loaded, the session stays on the old one.
+    fn switch_input(&mut self, sample: bool) -> Result<String> {
+        let params = SolverParams {
+            dial_size: self.dial_size,
+            k: self.k,
+            threshold: self.threshold,
+        };
+        *self = ReplSession::load(self.year, self.day, sample, params)?;
+        Ok(format!(
+            "Loaded the {} input",
+            if sample { "sample" } else { "real" }
+        ))
+    }
+
+    /// `set <parameter> <value>`
+    fn set(&mut self, args: &[&str]) -> Result<String> {
+        let [name, value] = args else {
+            return Err(AocError::ParseError(format!(
+                "Usage: set <{}> <value>",
+                self.parameters().join("|")
+            )));
+        };
+        let value: usize = value
+            .parse()
+            .map_err(|_| AocError::ParseError(format!("Invalid value '{}'", value)))?;
+
+        match *name {
+            "dial_size" if self.parameters().contains(name) => {
+                self.dial_size = i32::try_from(value)
+                    .ok()
+                    .filter(|&size| size > 0)
+                    .ok_or_else(|| AocError::ParseError(format!("Invalid dial size '{}'", value)))?
+            }
+            "k" if self.parameters().contains(name) => self.k = value,
+            "threshold" if self.parameters().contains(name) => self.threshold = value,
+            _ => {
+                return Err(AocError::ParseError(format!(
+                    "Unknown parameter '{}' (day {} has: {})",
+                    name,
+                    self.day,
+                    self.parameters().join(", ")
+                )))
+            }
+        }
+        Ok(format!("{} = {}", name, value))
+    }
+
+    /// `trace [limit]`: Day 1's dial position after each rotation
+    fn trace(&self, args: &[&str]) -> Result<String> {
+        let limit = match args.first() {
+            Some(limit) => limit
+                .parse()
+                .map_err(|_| AocError::ParseError(format!("Invalid limit '{}'", limit)))?,
+            None => usize::MAX,
+        };
+
+        let steps = panic::catch(|| day01::trace(&self.input_part1, self.dial_size))?;
+        let mut out = String::new();
+        for step in steps.iter().take(limit) {
+            let _ = writeln!(
+                out,
+                "line {:>4}: {}{:<4} -> {:>2} ({} click{} at 0)",
+                step.line,
+                if step.direction == Dir4::Left {
+                    'L'
+                } else {
+                    'R'
+                },
+                step.distance,
+                step.position,
+                step.zero_clicks,
+                if step.zero_clicks == 1 { "" } else { "s" }
+            );
+        }
+        let _ = write!(
+            out,
+            "{} rotations, {} ending at 0, {} clicks at 0",
+            steps.len(),
+            steps.iter().filter(|s| s.position == 0).count(),
+            steps.iter().map(|s| s.zero_clicks).sum::<i32>()
+        );
+        Ok(out)
+    }
+
+    /// `query <id>...`: Day 5 freshness of each ID
+    fn query(&self, args: &[&str]) -> Result<String> {
+        let Some(database) = &self.database else {
+            return Err(AocError::ParseError(
+                "query is only available for day 5".to_string(),
+            ));
+        };
+        if args.is_empty() {
+            return Err(AocError::ParseError("Usage: query <id>...".to_string()));
+        }
+
+        let mut out = Vec::new();
+        for arg in args {
+            let id: u64 = arg
+                .parse()
+                .map_err(|_| AocError::ParseError(format!("Invalid ingredient ID: {}", arg)))?;
+            out.push(format!("{}: {}", id, database.classify(id)));
+        }
+        Ok(out.join("\n"))
+    }
+
+    /// `show`: what is loaded and the current parameters
+    fn show(&self) -> String {
+        let mut out = format!(
+            "Day {} of {} ({} input, part 2 input {})",
+            self.day,
+            self.year,
+            if self.sample { "sample" } else { "real" },
+            if self.input_part2.is_some() {
+                "loaded"
+            } else {
+                "missing"
+            }
+        );
+        for name in self.parameters() {
+            let value = match *name {
+                "dial_size" => self.dial_size as usize,
+                "k" => self.k,
+                _ => self.threshold,
+            };
+            let _ = write!(out, "\n{} = {}", name, value);
+        }
+        out
+    }
+
+    /// `help`: the commands for this day
+    fn help(&self) -> String {
+        let mut out = String::from(
+            "part1 / part2        solve a part with the current parameters\n\
+             show                 show the loaded day and parameters\n\
+             reload               re-read the input files\n\
+             sample / real        switch to the sample or real input\n\
+             quit                 leave the REPL",
+        );
+        match self.puzzle_day() {
+            Some(1) => out.push_str(
+                "\ntrace [N]            show the dial after each rotation\n\
+                 set dial_size <N>    positions on the dial (default 100)",
+            ),
+            Some(3) => {
+                out.push_str("\nset k <N>            batteries per bank for part2 (default 12)")
+            }
+            Some(4) => out.push_str(
+                "\nset threshold <N>    accessible below N neighbouring rolls (default 4)",
+            ),
+            Some(5) => out.push_str("\nquery <id>...        check IDs against the fresh ranges"),
+            _ => {}
+        }
+        out
+    }
+}
+
+/// Tab completion for command names and `set` parameters
+struct ReplHelper {
+    commands: Vec<&'static str>,
+    parameters: &'static [&'static str],
+}
+
+impl Completer for ReplHelper {
+    type Candidate = String;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _ctx: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<String>)> {
+        let line = &line[..pos];
+        let start = line.rfind(' ').map_or(0, |idx| idx + 1);
+        let word = &line[start..];
+
+        let options: &[&str] = match line[..start].split_whitespace().collect::<Vec<_>>()[..] {
+            [] => &self.commands,
+            ["set"] => self.parameters,
+            _ => &[],
+        };
+        let candidates = options
+            .iter()
+            .filter(|option| option.starts_with(word))
+            .map(|option| option.to_string())
+            .collect();
+        Ok((start, candidates))
+    }
+}
+
+impl Hinter for ReplHelper {
+    type Hint = String;
+}
+
+impl Highlighter for ReplHelper {}
+
+impl Validator for ReplHelper {}
+
+impl Helper for ReplHelper {}
+
+/// Runs an interactive session for a day until `quit` or end of input
+///
+/// Line history is kept in `.aoc/repl-history` under `root`. The session's
+/// parameters start at `params` and can be changed with `set`.
+///
+/// # Errors
+/// Returns the errors of `ReplSession::load`, or `AocError::IoError` if
+/// the terminal can't be read
+pub fn run(root: &Path, year: u16, day: u8, sample: bool, params: SolverParams) -> Result<()> {
+    let mut session = ReplSession::load(year, day, sample, params)?;
+    let readline_error = |e: ReadlineError| AocError::IoError(std::io::Error::other(e));
+
+    let mut editor: Editor<ReplHelper, DefaultHistory> = Editor::new().map_err(readline_error)?;
+    editor.set_helper(Some(ReplHelper {
+        commands: session.commands(),
+        parameters: session.parameters(),
+    }));
+    let history = root.join(STATE_DIR).join("repl-history");
+    // A missing history file just means this is the first session
+    let _ = editor.load_history(&history);
+
+    println!(
+        "Day {} REPL. Type `help` for commands, `quit` to leave.",
+        day
+    );
+    let prompt = format!("day{}> ", day);
+    loop {
+        let line = match editor.readline(&prompt) {
+            Ok(line) => line,
+            Err(ReadlineError::Interrupted | ReadlineError::Eof) => break,
+            Err(e) => return Err(readline_error(e)),
+        };
+        if !line.trim().is_empty() {
+            let _ = editor.add_history_entry(line.as_str());
+        }
+
+        match session.execute(&line) {
+            Ok(Some(output)) if output.is_empty() => {}
+            Ok(Some(output)) => println!("{}", output),
+            Ok(None) => break,
+            Err(e) => println!("Error: {}", e),
+        }
+    }
+
+    std::fs::create_dir_all(root.join(STATE_DIR))?;
+    editor.save_history(&history).map_err(readline_error)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session(day: u8) -> ReplSession {
+        ReplSession::load(2025, day, true, SolverParams::default()).unwrap()
+    }
+
+    fn run(session: &mut ReplSession, line: &str) -> String {
+        session.execute(line).unwrap().unwrap()
+    }
+
+    #[test]
+    fn set_changes_what_the_parts_solve() {
+        let mut day4 = session(4);
+        assert!(run(&mut day4, "part1").starts_with("Part 1: 13 ("));
+        assert_eq!(run(&mut day4, "set threshold 1"), "threshold = 1");
+        assert!(run(&mut day4, "show").ends_with("threshold = 1"));
+        let fewer = run(&mut day4, "part1");
+        assert!(!fewer.starts_with("Part 1: 13 ("), "{}", fewer);
+
+        let mut day1 = session(1);
+        assert_eq!(run(&mut day1, "set dial_size 5"), "dial_size = 5");
+        assert!(run(&mut day1, "part1").starts_with("Part 1: 5 ("));
+    }
+
+    #[test]
+    fn set_rejects_unknown_parameters_and_values() {
+        let mut day3 = session(3);
+        assert!(day3.execute("set threshold 5").is_err());
+        assert!(day3.execute("set k many").is_err());
+        assert!(day3.execute("set k").is_err());
+        assert!(session(1).execute("set dial_size 0").is_err());
+        // Day 2 has nothing to set
+        assert!(session(2).execute("set k 3").is_err());
+    }
+
+    #[test]
+    fn runs_commands_and_ends_on_quit() {
+        let mut day5 = session(5);
+        assert!(run(&mut day5, "part1").starts_with("Part 1: 3 ("));
+        assert!(run(&mut day5, "part2").starts_with("Part 2: 14 ("));
+        assert_eq!(
+            run(&mut day5, "query 1 5"),
+            "1: spoiled (nearest fresh range: 3-5, 2 away)\n5: fresh (in 3-5)"
+        );
+        assert_eq!(run(&mut day5, "  "), "");
+        assert!(day5.execute("trace").is_err(), "trace is day 1 only");
+        assert!(day5.execute("quit").unwrap().is_none());
+    }
+
+    #[test]
+    fn switches_between_real_and_sample_input() {
+        // Built by hand so the test doesn't need a real input on disk
+        let mut day1 = session(1);
+        day1.sample = false;
+        day1.input_part1 = "R50\n".to_string();
+        assert_eq!(run(&mut day1, "set dial_size 5"), "dial_size = 5");
+        assert!(run(&mut day1, "show").starts_with("Day 1 of 2025 (real input"));
+        assert!(run(&mut day1, "part1").starts_with("Part 1: 1 ("));
+
+        assert_eq!(run(&mut day1, "sample"), "Loaded the sample input");
+        assert!(run(&mut day1, "show").starts_with("Day 1 of 2025 (sample input"));
+        assert!(run(&mut day1, "show").ends_with("dial_size = 5"));
+        assert!(run(&mut day1, "part1").starts_with("Part 1: 5 ("));
+    }
+
+    #[test]
+    fn a_failed_switch_keeps_the_loaded_input() {
+        // Day 6 has neither a sample nor a real input
+        let mut day6 = session(5);
+        day6.day = 6;
+        day6.database = None;
+        assert!(matches!(
+            day6.execute("real"),
+            Err(AocError::InputNotFound(6, _))
+        ));
+        assert!(run(&mut day6, "show").starts_with("Day 6 of 2025 (sample input"));
+        assert_eq!(day6.input_part1, session(5).input_part1);
+    }
+}
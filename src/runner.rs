@@ -0,0 +1,386 @@
+use crate::alloc::{self, AllocStats};
+use crate::days;
+use crate::utils::{calendar, input_path, read_input, AocError, CancelToken, Result};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// One part of one day to solve
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PartJob {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub sample: bool,
+}
+
+/// The result of a `PartJob`
+#[derive(Debug)]
+pub struct PartOutcome {
+    pub job: PartJob,
+    /// The answer, or why there isn't one (a missing input, a solver
+    /// error or a solver panic)
+    pub answer: Result<String>,
+    /// Time spent in the solver, excluding reading the input
+    pub elapsed: Duration,
+    /// The solver's allocations, with the `alloc-stats` feature
+    pub alloc: Option<AllocStats>,
+}
+
+/// Timings of a part solved several times by `bench_part`
+#[derive(Debug)]
+pub struct BenchOutcome {
+    pub job: PartJob,
+    /// The answer from the last run, or the error that stopped the benchmark
+    pub answer: Result<String>,
+    /// Time spent in the solver on each completed run, fastest first
+    pub times: Vec<Duration>,
+    /// The last run's allocations, with the `alloc-stats` feature
+    pub alloc: Option<AllocStats>,
+}
+
+impl BenchOutcome {
+    /// The fastest run
+    pub fn min(&self) -> Duration {
+        self.times.first().copied().unwrap_or_default()
+    }
+
+    /// The slowest run
+    pub fn max(&self) -> Duration {
+        self.times.last().copied().unwrap_or_default()
+    }
+
+    /// The middle run (the faster of the two middle runs for an even count)
+    pub fn median(&self) -> Duration {
+        match self.times.len() {
+            0 => Duration::ZERO,
+            len => self.times[(len - 1) / 2],
+        }
+    }
+
+    /// The average run
+    pub fn mean(&self) -> Duration {
+        match u32::try_from(self.times.len()) {
+            Ok(0) | Err(_) => Duration::ZERO,
+            Ok(runs) => self.times.iter().sum::<Duration>() / runs,
+        }
+    }
+}
+
+/// The implemented parts of one day, or of every day of the year
+///
+/// Days that haven't been released yet are skipped when `day` is `None`.
+///
+/// # Errors
+/// Returns `AocError::InvalidYear` or `AocError::InvalidDay` for a year or
+/// day outside the calendar, or `AocError::NotReleased` if `day` is locked
+pub fn implemented_jobs(year: u16, day: Option<u8>, sample: bool) -> Result<Vec<PartJob>> {
+    let days = match day {
+        Some(day) => {
+            calendar::check_released(year, day)?;
+            day..=day
+        }
+        None => calendar::valid_days(year).ok_or(AocError::InvalidYear(year))?,
+    };
+
+    let mut jobs = Vec::new();
+    for day in days {
+        let info = days::day_info(year, day)?;
+        if calendar::check_released(year, day).is_err() {
+            continue;
+        }
+        let implemented = [info.part1_implemented, info.part2_implemented];
+        for part in (1..=info.parts).filter(|&part| implemented[usize::from(part - 1)]) {
+            jobs.push(PartJob {
+                year,
+                day,
+                part,
+                sample,
+            });
+        }
+    }
+    Ok(jobs)
+}
+
+/// Reads a job's input and solves it
+///
+/// A panic in the solver is reported as that part's `AocError::SolverPanic`
+/// (see `days::solve_part1`), so one broken day can't abort the others.
+///
+/// # Arguments
+/// * `job` - The part to solve
+/// * `timeout` - How long the solver may run (see `solve_input`)
+pub fn solve_part(job: PartJob, timeout: Option<Duration>) -> PartOutcome {
+    let PartJob {
+        year,
+        day,
+        part,
+        sample,
+    } = job;
+
+    let input = match read_input(year, day, part, sample) {
+        Ok(input) => input,
+        Err(e) => {
+            return PartOutcome {
+                job,
+                answer: Err(e),
+                elapsed: Duration::ZERO,
+                alloc: None,
+            }
+        }
+    };
+
+    let (answer, elapsed, alloc) = solve_input(year, day, part, &input, timeout);
+    let answer = answer.map_err(|e| match input_path(year, day, part, sample) {
+        Ok(path) => e.with_path(path.display().to_string()),
+        Err(_) => e,
+    });
+
+    PartOutcome {
+        job,
+        answer,
+        elapsed,
+        alloc,
+    }
+}
+
+/// Solves one part of a day on an input that has already been read
+///
+/// With a `timeout`, the solver runs on its own thread and this returns
+/// `AocError::Timeout` as soon as the limit passes. The solver's
+/// `CancelToken` is cancelled at the same time, so a solver that calls
+/// `utils::checkpoint` stops shortly after; one that doesn't keeps its
+/// thread busy until it finishes, but its answer is discarded.
+///
+/// # Returns
+/// The answer (or error), the time spent in the solver and, with the
+/// `alloc-stats` feature, the solver's allocations (none if it timed out)
+pub fn solve_input(
+    year: u16,
+    day: u8,
+    part: u8,
+    input: &str,
+    timeout: Option<Duration>,
+) -> (Result<String>, Duration, Option<AllocStats>) {
+    let started = Instant::now();
+    let Some(limit) = timeout else {
+        let (answer, alloc) = alloc::measure(|| solve(year, day, part, input));
+        return (answer, started.elapsed(), alloc);
+    };
+
+    let token = CancelToken::new();
+    let input = input.to_string();
+    let (sender, receiver) = mpsc::channel();
+    let worker_token = token.clone();
+    let spawned = thread::Builder::new()
+        .name(format!("day{}p{}", day, part))
+        .spawn(move || {
+            worker_token.install();
+            let started = Instant::now();
+            let (answer, alloc) = alloc::measure(|| solve(year, day, part, &input));
+            // The receiver is gone if the part already timed out
+            let _ = sender.send((answer, started.elapsed(), alloc));
+        });
+    if let Err(e) = spawned {
+        return (Err(AocError::IoError(e)), Duration::ZERO, None);
+    }
+
+    match receiver.recv_timeout(limit) {
+        Ok(finished) => finished,
+        Err(RecvTimeoutError::Timeout) => {
+            token.cancel();
+            let elapsed = started.elapsed();
+            (Err(AocError::Timeout(elapsed)), elapsed, None)
+        }
+        Err(RecvTimeoutError::Disconnected) => (
+            Err(AocError::SolutionError(
+                "solver thread exited without an answer".to_string(),
+            )),
+            started.elapsed(),
+            None,
+        ),
+    }
+}
+
+/// Dispatches to the part's solver
+fn solve(year: u16, day: u8, part: u8, input: &str) -> Result<String> {
+    match part {
+        1 => days::solve_part1(year, day, input),
+        _ => days::solve_part2(year, day, input),
+    }
+}
+
+/// Solves every job on a pool of `threads` worker threads
+///
+/// Workers take the next unstarted job until none are left, so a slow
+/// day doesn't hold up the rest of the queue.
+///
+/// # Arguments
+/// * `jobs` - The parts to solve
+/// * `threads` - Number of worker threads
+/// * `timeout` - Per-part time limit (see `solve_input`)
+///
+/// # Returns
+/// One outcome per job, in the same order as `jobs` regardless of which
+/// finished first
+pub fn run_jobs(jobs: &[PartJob], threads: usize, timeout: Option<Duration>) -> Vec<PartOutcome> {
+    let next = AtomicUsize::new(0);
+    let threads = threads.clamp(1, jobs.len().max(1));
+
+    let mut outcomes: Vec<(usize, PartOutcome)> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..threads)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut done = Vec::new();
+                    loop {
+                        let idx = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(&job) = jobs.get(idx) else {
+                            break;
+                        };
+                        done.push((idx, solve_part(job, timeout)));
+                    }
+                    done
+                })
+            })
+            .collect();
+
+        workers
+            .into_iter()
+            // Solver panics are caught by `days::solve_part1` and `solve_part2`, so workers only end normally
+            .flat_map(|worker| worker.join().unwrap_or_default())
+            .collect()
+    });
+
+    outcomes.sort_by_key(|&(idx, _)| idx);
+    outcomes.into_iter().map(|(_, outcome)| outcome).collect()
+}
+
+/// Reads a job's input once and solves it `runs` times
+///
+/// The benchmark stops at the first run that fails, keeping the times of
+/// the runs before it.
+///
+/// # Arguments
+/// * `job` - The part to benchmark
+/// * `runs` - How many times to solve it
+/// * `timeout` - Per-run time limit (see `solve_input`)
+pub fn bench_part(job: PartJob, runs: usize, timeout: Option<Duration>) -> BenchOutcome {
+    let PartJob {
+        year,
+        day,
+        part,
+        sample,
+    } = job;
+
+    let input = match read_input(year, day, part, sample) {
+        Ok(input) => input,
+        Err(e) => {
+            return BenchOutcome {
+                job,
+                answer: Err(e),
+                times: Vec::new(),
+                alloc: None,
+            }
+        }
+    };
+
+    let mut times = Vec::with_capacity(runs);
+    let mut last = (Err(AocError::SolutionError("no runs".to_string())), None);
+    for _ in 0..runs {
+        let (answer, elapsed, alloc) = solve_input(year, day, part, &input, timeout);
+        let failed = answer.is_err();
+        last = (answer, alloc);
+        if failed {
+            break;
+        }
+        times.push(elapsed);
+    }
+    times.sort();
+
+    let (answer, alloc) = last;
+    BenchOutcome {
+        job,
+        answer: answer.map_err(|e| match input_path(year, day, part, sample) {
+            Ok(path) => e.with_path(path.display().to_string()),
+            Err(_) => e,
+        }),
+        times,
+        alloc,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solve_input_times_out_a_solver_that_checks_in() {
+        // Day 2 scans every ID in the range, calling `checkpoint` for each
+        let endless = "1-10000000000000000";
+        let limit = Duration::from_millis(50);
+
+        let (answer, elapsed, alloc) = solve_input(2025, 2, 1, endless, Some(limit));
+        match answer {
+            Err(AocError::Timeout(after)) => assert!(after >= limit),
+            other => panic!("expected a timeout, got {:?}", other),
+        }
+        assert!(elapsed >= limit && elapsed < Duration::from_secs(5));
+        assert_eq!(alloc, None);
+    }
+
+    #[test]
+    fn solve_input_within_the_limit_returns_the_answer() {
+        let (answer, _, _) = solve_input(2025, 2, 1, "11-22", Some(Duration::from_secs(5)));
+        assert_eq!(answer.unwrap(), "33");
+    }
+
+    #[test]
+    fn run_jobs_keeps_job_order() {
+        // Day 4 part 2 is the slowest, so with several threads it finishes last
+        let parts = [
+            (4, 2),
+            (2, 1),
+            (5, 1),
+            (3, 2),
+            (4, 1),
+            (2, 2),
+            (5, 2),
+            (3, 1),
+        ];
+        let jobs: Vec<PartJob> = parts
+            .iter()
+            .map(|&(day, part)| PartJob {
+                year: 2025,
+                day,
+                part,
+                sample: true,
+            })
+            .collect();
+
+        let outcomes = run_jobs(&jobs, 4, None);
+        let order: Vec<PartJob> = outcomes.iter().map(|outcome| outcome.job).collect();
+        assert_eq!(order, jobs);
+        assert!(outcomes.iter().all(|outcome| outcome.answer.is_ok()));
+    }
+
+    #[test]
+    fn run_jobs_reports_failures_in_place() {
+        let job = |day| PartJob {
+            year: 2025,
+            day,
+            part: 1,
+            sample: true,
+        };
+        // Day 12 isn't implemented, so its input is missing
+        let jobs = [job(2), job(12), job(3)];
+
+        let outcomes = run_jobs(&jobs, 2, None);
+        assert!(outcomes[0].answer.is_ok());
+        assert!(matches!(
+            outcomes[1].answer,
+            Err(AocError::InputNotFound(_, _))
+        ));
+        assert!(outcomes[2].answer.is_ok());
+    }
+}
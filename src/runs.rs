@@ -0,0 +1,363 @@
+use crate::alloc::AllocStats;
+use crate::api::STATE_DIR;
+use crate::history::{TsvHistory, TsvRecord};
+use crate::utils::Result;
+use std::path::Path;
+use std::time::Duration;
+
+/// One measured solver run
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunRecord {
+    /// Seconds since the Unix epoch
+    pub timestamp: u64,
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// Whether the run used the sample input
+    pub sample: bool,
+    pub elapsed: Duration,
+    /// Allocation counts, for runs built with the `alloc-stats` feature
+    pub alloc: Option<AllocStats>,
+}
+
+impl RunRecord {
+    /// Whether two runs measure the same part on the same kind of input
+    fn same_part(&self, other: &RunRecord) -> bool {
+        (self.year, self.day, self.part, self.sample)
+            == (other.year, other.day, other.part, other.sample)
+    }
+}
+
+/// Stored as `timestamp\tyear\tday\tpart\tinput\tnanos`, optionally
+/// followed by `\tallocations\tbytes\tpeak`
+impl TsvRecord for RunRecord {
+    const KIND: &'static str = "run history";
+
+    fn to_fields(&self) -> Vec<String> {
+        let mut fields = vec![
+            self.timestamp.to_string(),
+            self.year.to_string(),
+            self.day.to_string(),
+            self.part.to_string(),
+            if self.sample { "sample" } else { "real" }.to_string(),
+            self.elapsed.as_nanos().to_string(),
+        ];
+        fields.extend(alloc_fields(self.alloc));
+        fields
+    }
+
+    fn from_fields(fields: &[&str]) -> Option<Self> {
+        let (base, alloc) = split_alloc(fields, 6)?;
+        Some(RunRecord {
+            timestamp: base[0].parse().ok()?,
+            year: base[1].parse().ok()?,
+            day: base[2].parse().ok()?,
+            part: base[3].parse().ok()?,
+            sample: parse_input_kind(base[4])?,
+            elapsed: Duration::from_nanos(base[5].parse().ok()?),
+            alloc: parse_alloc(alloc)?,
+        })
+    }
+}
+
+/// One `bench` of a part: the spread of its timed runs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BenchRecord {
+    /// Seconds since the Unix epoch
+    pub timestamp: u64,
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// Whether the benchmark used the sample input
+    pub sample: bool,
+    /// Number of timed runs
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub max: Duration,
+    /// Allocation counts of the last run, with the `alloc-stats` feature
+    pub alloc: Option<AllocStats>,
+}
+
+/// Stored as `timestamp\tyear\tday\tpart\tinput\truns\tmin\tmedian\tmean\tmax`
+/// (times in nanoseconds), optionally followed by `\tallocations\tbytes\tpeak`
+impl TsvRecord for BenchRecord {
+    const KIND: &'static str = "benchmark history";
+
+    fn to_fields(&self) -> Vec<String> {
+        let mut fields = vec![
+            self.timestamp.to_string(),
+            self.year.to_string(),
+            self.day.to_string(),
+            self.part.to_string(),
+            if self.sample { "sample" } else { "real" }.to_string(),
+            self.runs.to_string(),
+        ];
+        fields.extend(
+            [self.min, self.median, self.mean, self.max].map(|time| time.as_nanos().to_string()),
+        );
+        fields.extend(alloc_fields(self.alloc));
+        fields
+    }
+
+    fn from_fields(fields: &[&str]) -> Option<Self> {
+        let (base, alloc) = split_alloc(fields, 10)?;
+        let nanos = |field: &str| field.parse().ok().map(Duration::from_nanos);
+        Some(BenchRecord {
+            timestamp: base[0].parse().ok()?,
+            year: base[1].parse().ok()?,
+            day: base[2].parse().ok()?,
+            part: base[3].parse().ok()?,
+            sample: parse_input_kind(base[4])?,
+            runs: base[5].parse().ok()?,
+            min: nanos(base[6])?,
+            median: nanos(base[7])?,
+            mean: nanos(base[8])?,
+            max: nanos(base[9])?,
+            alloc: parse_alloc(alloc)?,
+        })
+    }
+}
+
+/// The `allocations`, `bytes` and `peak` columns, if there are statistics
+fn alloc_fields(alloc: Option<AllocStats>) -> Vec<String> {
+    alloc
+        .map(|alloc| [alloc.allocations, alloc.bytes, alloc.peak].map(|n| n.to_string()))
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+/// Splits a line's fields into the `base` leading ones and the optional
+/// three allocation columns (`None` for any other field count)
+fn split_alloc<'a>(fields: &'a [&'a str], base: usize) -> Option<(&'a [&'a str], &'a [&'a str])> {
+    (fields.len() == base || fields.len() == base + 3).then(|| fields.split_at(base))
+}
+
+/// Parses the allocation columns: `Some(None)` if there are none, `None` if malformed
+fn parse_alloc(fields: &[&str]) -> Option<Option<AllocStats>> {
+    match fields {
+        [] => Some(None),
+        [allocations, bytes, peak] => Some(Some(AllocStats {
+            allocations: allocations.parse().ok()?,
+            bytes: bytes.parse().ok()?,
+            peak: peak.parse().ok()?,
+        })),
+        _ => None,
+    }
+}
+
+/// Parses the `sample` / `real` input column
+fn parse_input_kind(field: &str) -> Option<bool> {
+    match field {
+        "sample" => Some(true),
+        "real" => Some(false),
+        _ => None,
+    }
+}
+
+/// The latest run of every part, stored as tab-separated lines in `.aoc/runs.tsv`
+///
+/// Recording a run replaces the previous run of the same day, part and kind
+/// of input, so the file stays at one line per part. Benchmarks are kept
+/// separately, in `BenchHistory`.
+pub struct RunHistory {
+    history: TsvHistory<RunRecord>,
+}
+
+impl RunHistory {
+    /// Loads the history from the project root (an absent file is an empty history)
+    ///
+    /// # Errors
+    /// Returns `AocError::IoError` if the file exists but can't be read, or
+    /// `AocError::ParseError` if a line is malformed
+    pub fn load(root: &Path) -> Result<Self> {
+        let history = TsvHistory::open(root.join(STATE_DIR).join("runs.tsv"))?;
+        Ok(RunHistory { history })
+    }
+
+    /// The most recent run of a day and part on the given kind of input
+    pub fn latest(&self, year: u16, day: u8, part: u8, sample: bool) -> Option<&RunRecord> {
+        self.history
+            .entries()
+            .iter()
+            .rev()
+            .find(|r| r.year == year && r.day == day && r.part == part && r.sample == sample)
+    }
+
+    /// Records a run, replacing the previous run of the same part
+    ///
+    /// Older files that kept every run are compacted to the latest run of
+    /// each part at the same time.
+    ///
+    /// # Errors
+    /// Returns `AocError::IoError` if the file can't be written
+    pub fn record(&mut self, run: RunRecord) -> Result<()> {
+        let mut entries: Vec<RunRecord> = Vec::new();
+        for old in self.history.entries().iter().chain([&run]) {
+            entries.retain(|r| !r.same_part(old));
+            entries.push(old.clone());
+        }
+        self.history.rewrite(entries)
+    }
+}
+
+/// Every benchmark ever run, appended to `.aoc/bench.tsv`
+///
+/// Unlike `RunHistory`, nothing is replaced: a `run` of a part never touches
+/// its benchmarks, and earlier benchmarks stay for comparison.
+pub struct BenchHistory {
+    history: TsvHistory<BenchRecord>,
+}
+
+impl BenchHistory {
+    /// Loads the history from the project root (an absent file is an empty history)
+    ///
+    /// # Errors
+    /// Returns `AocError::IoError` if the file exists but can't be read, or
+    /// `AocError::ParseError` if a line is malformed
+    pub fn load(root: &Path) -> Result<Self> {
+        let history = TsvHistory::open(root.join(STATE_DIR).join("bench.tsv"))?;
+        Ok(BenchHistory { history })
+    }
+
+    /// Every benchmark of a day and part on the given kind of input, oldest first
+    pub fn part(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        sample: bool,
+    ) -> impl Iterator<Item = &BenchRecord> {
+        self.history
+            .entries()
+            .iter()
+            .filter(move |r| r.year == year && r.day == day && r.part == part && r.sample == sample)
+    }
+
+    /// Appends a benchmark
+    ///
+    /// # Errors
+    /// Returns `AocError::IoError` if the file can't be written
+    pub fn record(&mut self, bench: BenchRecord) -> Result<()> {
+        self.history.append(bench)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::history::TsvRecord;
+    use std::fs;
+
+    fn run(day: u8, sample: bool, millis: u64) -> RunRecord {
+        RunRecord {
+            timestamp: 1_764_565_200 + millis,
+            year: 2025,
+            day,
+            part: 1,
+            sample,
+            elapsed: Duration::from_millis(millis),
+            alloc: None,
+        }
+    }
+
+    #[test]
+    fn keeps_only_the_latest_run_per_part() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+
+        // A file from before compaction, with every run kept
+        let old_runs: String = [5, 3, 4]
+            .map(|millis| run(1, false, millis).to_fields().join("\t") + "\n")
+            .concat();
+        fs::create_dir_all(root.join(STATE_DIR)).unwrap();
+        fs::write(root.join(STATE_DIR).join("runs.tsv"), old_runs).unwrap();
+
+        let mut history = RunHistory::load(root).unwrap();
+        history.record(run(1, true, 1)).unwrap();
+
+        let reloaded = RunHistory::load(root).unwrap();
+        assert_eq!(reloaded.history.entries().len(), 2);
+        assert_eq!(reloaded.latest(2025, 1, 1, false), Some(&run(1, false, 4)));
+        assert_eq!(reloaded.latest(2025, 1, 1, true), Some(&run(1, true, 1)));
+    }
+
+    fn bench(millis: u64, alloc: Option<AllocStats>) -> BenchRecord {
+        let time = Duration::from_millis(millis);
+        BenchRecord {
+            timestamp: 1_764_565_200 + millis,
+            year: 2025,
+            day: 4,
+            part: 2,
+            sample: false,
+            runs: 10,
+            min: time,
+            median: time,
+            mean: time,
+            max: time,
+            alloc,
+        }
+    }
+
+    #[test]
+    fn benchmarks_are_kept_apart_from_runs() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let stats = AllocStats {
+            allocations: 10,
+            bytes: 2048,
+            peak: 512,
+        };
+
+        let mut benches = BenchHistory::load(root).unwrap();
+        benches.record(bench(5, Some(stats))).unwrap();
+        benches.record(bench(4, Some(stats))).unwrap();
+        let mut runs = RunHistory::load(root).unwrap();
+        runs.record(RunRecord {
+            day: 4,
+            part: 2,
+            ..run(4, false, 3)
+        })
+        .unwrap();
+
+        let reloaded = BenchHistory::load(root).unwrap();
+        assert_eq!(
+            reloaded.part(2025, 4, 2, false).collect::<Vec<_>>(),
+            [&bench(5, Some(stats)), &bench(4, Some(stats))]
+        );
+        assert_eq!(reloaded.part(2025, 4, 2, true).count(), 0);
+        assert_eq!(
+            RunHistory::load(root)
+                .unwrap()
+                .latest(2025, 4, 2, false)
+                .map(|r| r.alloc),
+            Some(None)
+        );
+    }
+
+    #[test]
+    fn reads_benchmark_lines_with_and_without_allocations() {
+        let fields = ["1", "2025", "4", "2", "sample", "3", "10", "20", "25", "45"];
+        let record = BenchRecord::from_fields(&fields).unwrap();
+        assert_eq!(record.median, Duration::from_nanos(20));
+        assert_eq!(record.alloc, None);
+        assert_eq!(record.to_fields(), fields);
+
+        let with_alloc = [&fields[..], &["10", "2048", "512"]].concat();
+        let record = BenchRecord::from_fields(&with_alloc).unwrap();
+        assert_eq!(record.alloc.map(|a| a.bytes), Some(2048));
+        assert_eq!(record.to_fields(), with_alloc);
+        assert!(BenchRecord::from_fields(&with_alloc[..11]).is_none());
+    }
+
+    #[test]
+    fn reads_allocation_columns() {
+        let fields = ["1", "2025", "4", "2", "real", "1500", "10", "2048", "512"];
+        let record = RunRecord::from_fields(&fields).unwrap();
+        assert_eq!(record.alloc.map(|a| a.peak), Some(512));
+        assert_eq!(record.to_fields(), fields);
+        assert!(RunRecord::from_fields(&fields[..7]).is_none());
+    }
+}
@@ -0,0 +1,156 @@
+use crate::utils::html::{decode_entities, elements, to_text};
+use crate::utils::{calendar, expected_path, input_path, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Sample files handled by `write_samples`
+#[derive(Debug, Default)]
+pub struct SamplesReport {
+    /// Sample inputs and `.expected` files that were written
+    pub written: Vec<PathBuf>,
+    /// Files that already existed and were kept (without `force`)
+    pub skipped: Vec<PathBuf>,
+}
+
+/// The example from one part of a puzzle description
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PuzzleSample {
+    /// Part number (1 or 2)
+    pub part: u8,
+    /// The example input, from the article's first `<pre><code>` block
+    pub input: String,
+    /// The example's answer, from the article's last `<code><em>` span
+    pub expected: Option<String>,
+}
+
+/// Extracts the example input and answer for each part from a puzzle page
+///
+/// Each part is an `<article>`; the example input is its first
+/// `<pre><code>` block and the expected answer is the last emphasized
+/// code span (`<code><em>…</em></code>`), which is where the puzzle text
+/// states the example's result. Part 2 often reuses part 1's example, so
+/// it falls back to part 1's input when its article has no code block.
+///
+/// # Arguments
+/// * `html` - The saved or fetched puzzle page
+///
+/// # Returns
+/// One sample per article found (at most two)
+pub fn extract_samples(html: &str) -> Vec<PuzzleSample> {
+    let mut samples: Vec<PuzzleSample> = Vec::new();
+
+    for (idx, article) in elements(html, "article").into_iter().take(2).enumerate() {
+        let input = elements(article, "pre")
+            .into_iter()
+            .find_map(|pre| elements(pre, "code").into_iter().next())
+            .map(to_text)
+            .or_else(|| samples.first().map(|s| s.input.clone()));
+
+        // The answer is the last `<code><em>…</em></code>` (or `<em><code>…`) span
+        let expected = ["<code><em>", "<em><code>"]
+            .iter()
+            .flat_map(|open| {
+                article.match_indices(open).filter_map(move |(pos, _)| {
+                    let body = &article[pos + open.len()..];
+                    let answer = decode_entities(body[..body.find('<')?].trim());
+                    (!answer.is_empty()).then_some((pos, answer))
+                })
+            })
+            .max_by_key(|&(pos, _)| pos)
+            .map(|(_, answer)| answer);
+
+        if let Some(input) = input {
+            samples.push(PuzzleSample {
+                part: idx as u8 + 1,
+                input,
+                expected,
+            });
+        }
+    }
+
+    samples
+}
+
+/// Writes sample inputs and expected-answer sidecars for a day
+///
+/// Inputs go to `inputs/YYYY/dayNpPs.txt` (the names `read_input` expects)
+/// and answers to `inputs/YYYY/dayNpPs.expected`. Existing files are kept
+/// unless `force` is set. A second sample on a single-part day is ignored.
+///
+/// # Errors
+/// Returns `AocError::InvalidYear` or `AocError::InvalidDay` for a day
+/// outside the calendar, or `AocError::IoError` if a file can't be written
+pub fn write_samples(
+    root: &Path,
+    year: u16,
+    day: u8,
+    samples: &[PuzzleSample],
+    force: bool,
+) -> Result<SamplesReport> {
+    let mut report = SamplesReport::default();
+    let parts = calendar::parts_in_day(year, day)?;
+
+    for sample in samples.iter().filter(|s| s.part <= parts) {
+        let mut files = vec![(
+            input_path(year, day, sample.part, true)?,
+            sample.input.clone(),
+        )];
+        if let Some(expected) = &sample.expected {
+            files.push((
+                expected_path(year, day, sample.part, true)?,
+                format!("{}\n", expected),
+            ));
+        }
+
+        for (relative, contents) in files {
+            let path = root.join(relative);
+            if path.exists() && !force {
+                report.skipped.push(path);
+                continue;
+            }
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(&path, contents)?;
+            report.written.push(path);
+        }
+    }
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "a -> b\nb -> c\nc -> \"out\"\n";
+
+    #[test]
+    fn reads_the_first_code_block_and_last_emphasized_answer() {
+        let samples = extract_samples(include_str!("../tests/fixtures/puzzle-part1.html"));
+        assert_eq!(
+            samples,
+            [PuzzleSample {
+                part: 1,
+                input: EXAMPLE.to_string(),
+                expected: Some("3".to_string()),
+            }]
+        );
+    }
+
+    #[test]
+    fn part2_reuses_the_part1_example() {
+        let samples = extract_samples(include_str!("../tests/fixtures/puzzle-both-parts.html"));
+        assert_eq!(samples.len(), 2);
+        assert_eq!(samples[1].part, 2);
+        assert_eq!(samples[1].input, EXAMPLE);
+        assert_eq!(samples[1].expected.as_deref(), Some("6"));
+    }
+
+    #[test]
+    fn page_without_articles_has_no_samples() {
+        assert!(
+            extract_samples(include_str!("../tests/fixtures/answer-unrecognized.html")).is_empty()
+        );
+    }
+}
@@ -0,0 +1,299 @@
+use crate::days;
+use crate::utils::{calendar, input_path, AocError, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Files touched while scaffolding a new day
+#[derive(Debug, Default)]
+pub struct ScaffoldReport {
+    /// Files that were written (created or overwritten)
+    pub written: Vec<PathBuf>,
+    /// Files that already existed and were left alone
+    pub skipped: Vec<PathBuf>,
+}
+
+/// Generates the solver module, registry entry and input files for a new day
+///
+/// Creates `src/days/yYYYY/dayNN.rs` from a template, registers it in
+/// `src/days/yYYYY/mod.rs`, and creates empty `inputs/YYYY/dayNNp1.txt` and
+/// `inputs/YYYY/dayNp1s.txt` files. Existing input files are never touched.
+/// The registry edit is prepared before anything is written, and the solver
+/// module is rolled back if the year's `mod.rs` can't be updated.
+/// `year` must already have a solver module (see `days::has_solvers`).
+///
+/// # Arguments
+/// * `root` - The project root (the directory containing `src/` and `inputs/`)
+/// * `year` - The event year, whose solvers live in `days::source_dir(year)`
+/// * `day` - The day number (within the year's `calendar::valid_days`)
+/// * `force` - Overwrite an existing `dayNN.rs` instead of refusing
+///
+/// # Returns
+/// The files that were written and the ones that were skipped
+///
+/// # Errors
+/// Returns `AocError::InvalidDay` if day is not part of the event
+/// Returns `AocError::SolutionError` if no solvers are registered for the year
+/// Returns `AocError::AlreadyExists` if the solver already exists (without `force`)
+/// Returns `AocError::ParseError` if the registry in the year's `mod.rs` can't be located
+/// Returns `AocError::IoError` if any file can't be read or written
+pub fn scaffold_day(root: &Path, year: u16, day: u8, force: bool) -> Result<ScaffoldReport> {
+    calendar::check_day(year, day)?;
+    days::check_solver_year(year)?;
+
+    let mut report = ScaffoldReport::default();
+
+    // Solver module
+    let dir = root.join(days::source_dir(year));
+    let solver_path = dir.join(format!("day{:02}.rs", day));
+    if solver_path.exists() && !force {
+        return Err(AocError::AlreadyExists(solver_path.display().to_string()));
+    }
+
+    // Registry, prepared up front so a missing match fails before any write
+    let mod_path = dir.join("mod.rs");
+    let mod_rs = fs::read_to_string(&mod_path)?;
+    let registered = register_day(&mod_rs, day)?;
+
+    let previous = fs::read_to_string(&solver_path).ok();
+    fs::write(&solver_path, render_day(day))?;
+    let registered_changed = registered != mod_rs;
+    if registered_changed {
+        if let Err(e) = fs::write(&mod_path, registered) {
+            // Put the solver module back the way it was
+            match previous {
+                Some(contents) => fs::write(&solver_path, contents)?,
+                None => fs::remove_file(&solver_path)?,
+            }
+            return Err(e.into());
+        }
+    }
+    report.written.push(solver_path);
+    if registered_changed {
+        report.written.push(mod_path);
+    }
+
+    // Empty real and sample inputs, following `read_input`'s naming
+    for sample in [false, true] {
+        let path = root.join(input_path(year, day, 1, sample)?);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        if path.exists() {
+            report.skipped.push(path);
+        } else {
+            fs::write(&path, "")?;
+            report.written.push(path);
+        }
+    }
+
+    Ok(report)
+}
+
+/// Renders the solver template for a day
+pub fn render_day(day: u8) -> String {
+    DAY_TEMPLATE
+        .replace("{{DAY}}", &day.to_string())
+        .trim_start()
+        .to_string()
+}
+
+/// Adds `pub mod dayNN;` and a registry entry for `day` to the source of a year's `mod.rs`
+///
+/// The entry registers no solvers (`part1: None`, `part2: None`), so the day
+/// counts as unimplemented until each part is switched to its `solve_partN`.
+/// Days that are already registered are left unchanged.
+///
+/// # Errors
+/// Returns `AocError::ParseError` if the registry match can't be located
+pub fn register_day(mod_rs: &str, day: u8) -> Result<String> {
+    let module = format!("day{:02}", day);
+    let mod_decl = format!("pub mod {};", module);
+    let mut out = mod_rs.to_string();
+
+    // Module declaration, kept in numeric order with the existing ones
+    if !mod_rs.lines().any(|line| line.trim() == mod_decl) {
+        let mut lines: Vec<&str> = mod_rs.lines().collect();
+        let decls: Vec<usize> = (0..lines.len())
+            .filter(|&idx| lines[idx].starts_with("pub mod day"))
+            .collect();
+        let last = *decls.last().ok_or_else(|| {
+            AocError::ParseError("Could not find the day module declarations".to_string())
+        })?;
+
+        // Zero-padded names sort numerically as strings
+        let insert_at = decls
+            .into_iter()
+            .find(|&idx| lines[idx].trim() > mod_decl.as_str())
+            .unwrap_or(last + 1);
+        lines.insert(insert_at, &mod_decl);
+
+        out = lines.join("\n");
+        if mod_rs.ends_with('\n') {
+            out.push('\n');
+        }
+    }
+
+    // Registry entry, just before the day registry's catch-all arm
+    let arm = format!("        {} => Some(DayRegistry {{", day);
+    if !out.contains(&arm) {
+        let catch_all = out
+            .find("fn get_day_registry(")
+            .and_then(|start| Some(start + out[start..].find("        _ => None,")?))
+            .ok_or_else(|| {
+                AocError::ParseError("Could not find the day registry match".to_string())
+            })?;
+        let entry = format!(
+            "{arm}\n            title: None,\n            // Some({m}::solve_part1) / Some({m}::solve_part2) once implemented\n            part1: None,\n            part2: None,\n        }}),\n",
+            arm = arm,
+            m = module
+        );
+        out.insert_str(catch_all, &entry);
+    }
+
+    Ok(out)
+}
+
+/// Template for a new day's solver (`{{DAY}}` is replaced with the day number)
+const DAY_TEMPLATE: &str = r#"
+use crate::utils::{AocError, Result};
+
+/// Solves Part 1 of Day {{DAY}}
+///
+/// TODO: Describe the puzzle.
+///
+/// # Arguments
+/// * `input` - The puzzle input string
+///
+/// # Returns
+/// The solution as a String
+///
+/// # Errors
+/// Returns a `Result::Err` if there's an error parsing the input
+pub fn solve_part1(input: &str) -> Result<String> {
+    let _ = input;
+    Err(AocError::SolutionError(
+        "Part 1 not implemented for day {{DAY}}".to_string(),
+    ))
+}
+
+/// Solves Part 2 of Day {{DAY}}
+///
+/// TODO: Describe the puzzle.
+///
+/// # Arguments
+/// * `input` - The puzzle input string
+///
+/// # Returns
+/// The solution as a String
+///
+/// # Errors
+/// Returns a `Result::Err` if there's an error parsing the input
+pub fn solve_part2(input: &str) -> Result<String> {
+    let _ = input;
+    Err(AocError::SolutionError(
+        "Part 2 not implemented for day {{DAY}}".to_string(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// TODO: Paste the sample input from the puzzle description
+    const SAMPLE: &str = "";
+
+    #[test]
+    #[ignore = "fill in SAMPLE and the expected answer"]
+    fn part1_sample() {
+        assert_eq!(solve_part1(SAMPLE).unwrap(), "");
+    }
+
+    #[test]
+    #[ignore = "fill in SAMPLE and the expected answer"]
+    fn part2_sample() {
+        assert_eq!(solve_part2(SAMPLE).unwrap(), "");
+    }
+}
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A scratch project root with a `src/days/y2025/mod.rs`, removed on drop
+    fn project(mod_rs: &str) -> tempfile::TempDir {
+        let root = tempfile::tempdir().unwrap();
+        fs::create_dir_all(root.path().join("src/days/y2025")).unwrap();
+        fs::write(root.path().join("src/days/y2025/mod.rs"), mod_rs).unwrap();
+        root
+    }
+
+    #[test]
+    fn registers_new_days_as_unimplemented() {
+        let mod_rs = "\
+pub mod day01;
+pub mod day03;
+
+pub(super) fn get_day_registry(day: u8) -> Option<DayRegistry> {
+    match day {
+        1 => Some(DayRegistry {
+            title: Some(\"Dial\"),
+            part1: Some(day01::solve_part1),
+            part2: Some(day01::solve_part2),
+        }),
+        _ => None,
+    }
+}
+";
+        let expected = "\
+pub mod day01;
+pub mod day02;
+pub mod day03;
+
+pub(super) fn get_day_registry(day: u8) -> Option<DayRegistry> {
+    match day {
+        1 => Some(DayRegistry {
+            title: Some(\"Dial\"),
+            part1: Some(day01::solve_part1),
+            part2: Some(day01::solve_part2),
+        }),
+        2 => Some(DayRegistry {
+            title: None,
+            // Some(day02::solve_part1) / Some(day02::solve_part2) once implemented
+            part1: None,
+            part2: None,
+        }),
+        _ => None,
+    }
+}
+";
+        let registered = register_day(mod_rs, 2).unwrap();
+        assert_eq!(registered, expected);
+        assert_eq!(register_day(&registered, 2).unwrap(), expected);
+    }
+
+    #[test]
+    fn unregistrable_day_writes_nothing() {
+        let project = project("// no registry here\n");
+        let root = project.path();
+        let err = scaffold_day(root, 2025, 6, false).unwrap_err();
+        assert!(matches!(err, AocError::ParseError(_)), "{err:?}");
+        assert!(!root.join("src/days/y2025/day06.rs").exists());
+    }
+
+    #[test]
+    fn existing_solver_is_refused_without_force() {
+        let project = project("");
+        let root = project.path();
+        fs::write(root.join("src/days/y2025/day06.rs"), "// mine").unwrap();
+        let err = scaffold_day(root, 2025, 6, false).unwrap_err();
+        assert_eq!(
+            err.exit_code(),
+            crate::utils::error::exit_code::ALREADY_EXISTS
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("src/days/y2025/day06.rs")).unwrap(),
+            "// mine"
+        );
+    }
+}
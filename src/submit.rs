@@ -0,0 +1,394 @@
+use crate::api::STATE_DIR;
+use crate::history::{TsvHistory, TsvRecord};
+use crate::utils::{html, Result};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// The server's verdict on a submitted answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, with no hint about the direction
+    Wrong,
+    /// Submitted too soon after a previous attempt; holds the remaining wait
+    RateLimited(Duration),
+    /// The part was already solved (or isn't unlocked yet)
+    AlreadySolved,
+    /// The response couldn't be interpreted; holds the response text
+    Unknown(String),
+}
+
+impl Verdict {
+    /// Interprets the HTML page returned by the answer endpoint
+    pub fn from_response(html: &str) -> Verdict {
+        let text = article_text(html);
+
+        if text.contains("That's the right answer") {
+            Verdict::Correct
+        } else if text.contains("You gave an answer too recently") {
+            Verdict::RateLimited(parse_wait(&text))
+        } else if text.contains("You don't seem to be solving the right level") {
+            Verdict::AlreadySolved
+        } else if text.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if text.contains("your answer is too low") {
+            Verdict::TooLow
+        } else if text.contains("That's not the right answer") {
+            Verdict::Wrong
+        } else {
+            Verdict::Unknown(text)
+        }
+    }
+
+    /// Checks whether the verdict proves the answer wrong
+    pub fn is_incorrect(&self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+
+    /// Short, stable name used in the history file
+    fn key(&self) -> String {
+        match self {
+            Verdict::Correct => "correct".to_string(),
+            Verdict::TooHigh => "too-high".to_string(),
+            Verdict::TooLow => "too-low".to_string(),
+            Verdict::Wrong => "wrong".to_string(),
+            Verdict::RateLimited(wait) => format!("rate-limited:{}", wait.as_secs()),
+            Verdict::AlreadySolved => "already-solved".to_string(),
+            Verdict::Unknown(_) => "unknown".to_string(),
+        }
+    }
+
+    /// Parses a name written by `key`
+    fn from_key(key: &str) -> Option<Verdict> {
+        Some(match key {
+            "correct" => Verdict::Correct,
+            "too-high" => Verdict::TooHigh,
+            "too-low" => Verdict::TooLow,
+            "wrong" => Verdict::Wrong,
+            "already-solved" => Verdict::AlreadySolved,
+            "unknown" => Verdict::Unknown(String::new()),
+            _ => {
+                let secs = key.strip_prefix("rate-limited:")?.parse().ok()?;
+                Verdict::RateLimited(Duration::from_secs(secs))
+            }
+        })
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "wrong (too high)"),
+            Verdict::TooLow => write!(f, "wrong (too low)"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::RateLimited(wait) => {
+                write!(
+                    f,
+                    "rate limited, wait {}s before submitting again",
+                    wait.as_secs()
+                )
+            }
+            Verdict::AlreadySolved => write!(f, "already solved (or not unlocked yet)"),
+            Verdict::Unknown(text) => write!(f, "unrecognised response: {}", text),
+        }
+    }
+}
+
+/// Extracts the text of the page's `<article>`, with tags stripped
+fn article_text(html: &str) -> String {
+    let body = html::elements(html, "article")
+        .into_iter()
+        .next()
+        .unwrap_or(html);
+
+    html::to_text(body)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Parses the wait in "You have 1m 34s left to wait"
+///
+/// The rate-limit page also says "you have to wait", so only a "you have"
+/// followed by nothing but `h`/`m`/`s` amounts counts.
+fn parse_wait(text: &str) -> Duration {
+    let text = text.to_lowercase();
+    let secs = text.split("you have ").skip(1).find_map(|rest| {
+        let wait = rest.split(" left").next()?;
+        wait.split_whitespace()
+            .map(|part| {
+                let (value, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+                let value: u64 = value.parse().ok()?;
+                match unit {
+                    "h" => Some(value * 3600),
+                    "m" => Some(value * 60),
+                    "s" => Some(value),
+                    _ => None,
+                }
+            })
+            .sum::<Option<u64>>()
+    });
+
+    Duration::from_secs(secs.unwrap_or(0))
+}
+
+/// One recorded submission attempt
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    /// Seconds since the Unix epoch
+    pub timestamp: u64,
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Every submission attempt, stored as tab-separated lines in `.aoc/submissions.tsv`
+pub struct SubmissionHistory {
+    history: TsvHistory<Submission>,
+}
+
+impl SubmissionHistory {
+    /// Where the history is kept unless `answers` is set in `aoc.toml`
+    pub fn default_path(root: &Path) -> PathBuf {
+        root.join(STATE_DIR).join("submissions.tsv")
+    }
+
+    /// Loads the history from the project root (an absent file is an empty history)
+    ///
+    /// # Errors
+    /// Same as `open`
+    pub fn load(root: &Path) -> Result<Self> {
+        SubmissionHistory::open(SubmissionHistory::default_path(root))
+    }
+
+    /// Loads the history from a file (an absent file is an empty history)
+    ///
+    /// # Errors
+    /// Returns `AocError::IoError` if the file exists but can't be read, or
+    /// `AocError::ParseError` if a line is malformed
+    pub fn open(path: PathBuf) -> Result<Self> {
+        Ok(SubmissionHistory {
+            history: TsvHistory::open(path)?,
+        })
+    }
+
+    /// All recorded attempts for a day and part, oldest first
+    pub fn attempts(&self, year: u16, day: u8, part: u8) -> impl Iterator<Item = &Submission> {
+        self.history
+            .entries()
+            .iter()
+            .filter(move |s| s.year == year && s.day == day && s.part == part)
+    }
+
+    /// Explains why `answer` shouldn't be submitted, if the history already settles it
+    ///
+    /// Refuses answers already marked wrong, numeric answers at or beyond a
+    /// known too-high / too-low bound, and any answer once the part is solved.
+    pub fn refusal(&self, year: u16, day: u8, part: u8, answer: &str) -> Option<String> {
+        let numeric = answer.parse::<i128>().ok();
+
+        for attempt in self.attempts(year, day, part) {
+            let previous = attempt.answer.parse::<i128>().ok();
+            let reason = match (&attempt.verdict, numeric, previous) {
+                (Verdict::Correct, _, _) => {
+                    format!(
+                        "part already solved with a different answer ({})",
+                        attempt.answer
+                    )
+                }
+                (verdict, _, _) if verdict.is_incorrect() && attempt.answer == answer => {
+                    format!("{} was already submitted and is {}", answer, verdict)
+                }
+                (Verdict::TooHigh, Some(new), Some(old)) if new >= old => {
+                    format!("{} is too high ({} already was)", answer, old)
+                }
+                (Verdict::TooLow, Some(new), Some(old)) if new <= old => {
+                    format!("{} is too low ({} already was)", answer, old)
+                }
+                _ => continue,
+            };
+            return Some(reason);
+        }
+
+        None
+    }
+
+    /// Appends an attempt to the history file
+    ///
+    /// # Errors
+    /// Returns `AocError::IoError` if the file can't be written
+    pub fn record(&mut self, submission: Submission) -> Result<()> {
+        self.history.append(submission)
+    }
+}
+
+/// The year of history lines written before the year column was added
+const LEGACY_YEAR: u16 = 2025;
+
+/// Stored as `timestamp\tyear\tday\tpart\tanswer\tverdict`
+///
+/// Lines written before the year column was added have five fields and
+/// belong to `LEGACY_YEAR`.
+impl TsvRecord for Submission {
+    const KIND: &'static str = "submission history";
+
+    fn to_fields(&self) -> Vec<String> {
+        vec![
+            self.timestamp.to_string(),
+            self.year.to_string(),
+            self.day.to_string(),
+            self.part.to_string(),
+            self.answer.clone(),
+            self.verdict.key(),
+        ]
+    }
+
+    fn from_fields(fields: &[&str]) -> Option<Self> {
+        let (timestamp, year, rest) = match fields {
+            [timestamp, year, rest @ ..] if rest.len() == 4 => {
+                (timestamp, year.parse().ok()?, rest)
+            }
+            [timestamp, rest @ ..] if rest.len() == 4 => (timestamp, LEGACY_YEAR, rest),
+            _ => return None,
+        };
+        Some(Submission {
+            timestamp: timestamp.parse().ok()?,
+            year,
+            day: rest[0].parse().ok()?,
+            part: rest[1].parse().ok()?,
+            answer: rest[2].to_string(),
+            verdict: Verdict::from_key(rest[3])?,
+        })
+    }
+}
+
+/// Current time in seconds since the Unix epoch
+pub fn now_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_lines_with_and_without_a_year() {
+        let current = Submission::from_fields(&["100", "2024", "3", "2", "42", "too-low"]).unwrap();
+        assert_eq!((current.year, current.day, current.part), (2024, 3, 2));
+        assert_eq!(current.verdict, Verdict::TooLow);
+
+        let legacy = Submission::from_fields(&["100", "3", "2", "42", "too-low"]).unwrap();
+        assert_eq!(legacy.year, LEGACY_YEAR);
+        assert_eq!(legacy.answer, "42");
+
+        assert!(Submission::from_fields(&["100", "3", "2", "42"]).is_none());
+    }
+
+    /// A saved response page from `tests/fixtures`
+    macro_rules! fixture {
+        ($name:literal) => {
+            include_str!(concat!("../tests/fixtures/", $name))
+        };
+    }
+
+    #[test]
+    fn reads_verdicts_from_response_pages() {
+        assert_eq!(
+            Verdict::from_response(fixture!("answer-correct.html")),
+            Verdict::Correct
+        );
+        assert_eq!(
+            Verdict::from_response(fixture!("answer-too-high.html")),
+            Verdict::TooHigh
+        );
+        assert_eq!(
+            Verdict::from_response(fixture!("answer-too-low.html")),
+            Verdict::TooLow
+        );
+        assert_eq!(
+            Verdict::from_response(fixture!("answer-rate-limited.html")),
+            Verdict::RateLimited(Duration::from_secs(94))
+        );
+    }
+
+    #[test]
+    fn keeps_the_text_of_an_unrecognized_page() {
+        let Verdict::Unknown(text) = Verdict::from_response(fixture!("answer-unrecognized.html"))
+        else {
+            panic!("expected an unknown verdict");
+        };
+        assert!(
+            text.contains("Please log in to get your puzzle input."),
+            "{text}"
+        );
+        assert!(!text.contains('<'), "{text}");
+    }
+
+    #[test]
+    fn parses_waits() {
+        assert_eq!(
+            parse_wait("You have 1h 2m 3s left to wait."),
+            Duration::from_secs(3723)
+        );
+        assert_eq!(
+            parse_wait("You have 34s left to wait."),
+            Duration::from_secs(34)
+        );
+        assert_eq!(parse_wait("Please wait one minute."), Duration::ZERO);
+    }
+
+    fn history(attempts: &[(&str, Verdict)]) -> (tempfile::TempDir, SubmissionHistory) {
+        let dir = tempfile::tempdir().unwrap();
+        let mut history = SubmissionHistory::open(dir.path().join("submissions.tsv")).unwrap();
+        for (answer, verdict) in attempts {
+            history
+                .record(Submission {
+                    timestamp: 100,
+                    year: 2025,
+                    day: 1,
+                    part: 1,
+                    answer: answer.to_string(),
+                    verdict: verdict.clone(),
+                })
+                .unwrap();
+        }
+        (dir, history)
+    }
+
+    #[test]
+    fn refuses_answers_outside_known_bounds() {
+        let (_dir, history) = history(&[("500", Verdict::TooHigh), ("100", Verdict::TooLow)]);
+
+        let refusal = history.refusal(2025, 1, 1, "600").unwrap();
+        assert_eq!(refusal, "600 is too high (500 already was)");
+        let refusal = history.refusal(2025, 1, 1, "500").unwrap();
+        assert_eq!(refusal, "500 was already submitted and is wrong (too high)");
+        let refusal = history.refusal(2025, 1, 1, "99").unwrap();
+        assert_eq!(refusal, "99 is too low (100 already was)");
+
+        assert_eq!(history.refusal(2025, 1, 1, "300"), None);
+        assert_eq!(history.refusal(2025, 1, 1, "abc"), None);
+        // Other parts, days and years aren't bounded by these attempts
+        assert_eq!(history.refusal(2025, 1, 2, "600"), None);
+        assert_eq!(history.refusal(2024, 1, 1, "600"), None);
+    }
+
+    #[test]
+    fn refuses_everything_once_solved() {
+        let (_dir, history) = history(&[("x", Verdict::Wrong), ("42", Verdict::Correct)]);
+
+        let refusal = history.refusal(2025, 1, 1, "43").unwrap();
+        assert_eq!(refusal, "part already solved with a different answer (42)");
+        let refusal = history.refusal(2025, 1, 1, "x").unwrap();
+        assert_eq!(refusal, "x was already submitted and is wrong");
+    }
+}
@@ -0,0 +1,440 @@
+use crate::days::y2025::{self, day04};
+use crate::days::{self, DayInfo, SolverParams};
+use crate::runner::{self, PartJob};
+use crate::utils::{calendar, read_expected, read_input, AocError, Result};
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Paragraph};
+use ratatui::{DefaultTerminal, Frame};
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Days per row of the calendar grid
+const GRID_COLUMNS: usize = 4;
+
+/// The outcome of solving one part
+#[derive(Debug, Clone)]
+pub struct PartResult {
+    pub part: u8,
+    /// The answer, or the error message
+    pub answer: std::result::Result<String, String>,
+    pub elapsed: Duration,
+    /// The answer recorded in the input's `.expected` sidecar, if any
+    pub expected: Option<String>,
+}
+
+impl PartResult {
+    /// Whether the answer matches the recorded expected answer
+    pub fn status(&self) -> Status {
+        match (&self.answer, &self.expected) {
+            (Err(_), _) => Status::Failing,
+            (Ok(answer), Some(expected)) if answer == expected => Status::Solved,
+            (Ok(_), Some(_)) => Status::Failing,
+            (Ok(_), None) => Status::Unverified,
+        }
+    }
+}
+
+/// How a day looks on the calendar
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// Every part matches its expected answer
+    Solved,
+    /// A part errored or disagrees with its expected answer
+    Failing,
+    /// Every part has an answer but at least one has nothing to check it against
+    Unverified,
+    /// No part of the day is implemented
+    NotImplemented,
+    /// The puzzle hasn't unlocked yet
+    Locked,
+    /// Implemented but not run yet
+    NotRun,
+}
+
+impl Status {
+    fn color(self) -> Color {
+        match self {
+            Status::Solved => Color::Green,
+            Status::Failing => Color::Red,
+            Status::Unverified => Color::Yellow,
+            Status::NotImplemented | Status::NotRun => Color::Gray,
+            Status::Locked => Color::DarkGray,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Status::Solved => "solved",
+            Status::Failing => "failing",
+            Status::Unverified => "unverified",
+            Status::NotImplemented => "not implemented",
+            Status::Locked => "locked",
+            Status::NotRun => "not run",
+        }
+    }
+}
+
+/// Solves one part of a day and compares it with the expected answer
+///
+/// A missing input shows up as that part's failure.
+pub fn run_part(year: u16, day: u8, part: u8, sample: bool) -> PartResult {
+    let outcome = runner::solve_part(
+        PartJob {
+            year,
+            day,
+            part,
+            sample,
+        },
+        None,
+    );
+
+    PartResult {
+        part,
+        answer: outcome.answer.map_err(|e| e.to_string()),
+        elapsed: outcome.elapsed,
+        expected: read_expected(year, day, part, sample),
+    }
+}
+
+/// Dashboard state
+struct App {
+    year: u16,
+    sample: bool,
+    days: Vec<DayInfo>,
+    /// Index into `days`
+    selected: usize,
+    /// Results per day for the current input kind
+    results: HashMap<u8, Vec<PartResult>>,
+    show_visualization: bool,
+    /// Parameters for the visualizations (`[dayN]` in `aoc.toml`)
+    params: SolverParams,
+}
+
+impl App {
+    fn new(year: u16, sample: bool, focus: Option<u8>, params: SolverParams) -> Result<Self> {
+        if let Some(day) = focus {
+            calendar::check_day(year, day)?;
+        }
+        let days = calendar::valid_days(year)
+            .ok_or(AocError::InvalidYear(year))?
+            .map(|day| days::day_info(year, day))
+            .collect::<Result<Vec<_>>>()?;
+        let selected = focus.map_or(0, |day| usize::from(day - 1));
+
+        Ok(App {
+            year,
+            sample,
+            days,
+            selected,
+            results: HashMap::new(),
+            show_visualization: focus.is_some(),
+            params,
+        })
+    }
+
+    fn selected_day(&self) -> &DayInfo {
+        &self.days[self.selected]
+    }
+
+    /// Solves every implemented part of a day
+    fn run_day(&mut self, index: usize) {
+        let info = &self.days[index];
+        if calendar::check_released(info.year, info.day).is_err() {
+            return;
+        }
+
+        let implemented = [info.part1_implemented, info.part2_implemented];
+        let results = (1..=info.parts)
+            .filter(|&part| implemented[usize::from(part - 1)])
+            .map(|part| run_part(self.year, info.day, part, self.sample))
+            .collect();
+        self.results.insert(info.day, results);
+    }
+
+    fn run_all(&mut self) {
+        for index in 0..self.days.len() {
+            self.run_day(index);
+        }
+    }
+
+    fn status(&self, info: &DayInfo) -> Status {
+        if calendar::check_released(info.year, info.day).is_err() {
+            return Status::Locked;
+        }
+        if !info.part1_implemented && !info.part2_implemented {
+            return Status::NotImplemented;
+        }
+        let Some(results) = self.results.get(&info.day) else {
+            return Status::NotRun;
+        };
+
+        let statuses: Vec<Status> = results.iter().map(PartResult::status).collect();
+        if statuses.contains(&Status::Failing) {
+            Status::Failing
+        } else if statuses.contains(&Status::Unverified) {
+            Status::Unverified
+        } else {
+            Status::Solved
+        }
+    }
+
+    fn draw(&self, frame: &mut Frame) {
+        let [header, body, footer] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Min(0),
+            Constraint::Length(1),
+        ])
+        .areas(frame.area());
+
+        frame.render_widget(
+            Paragraph::new(Line::from(vec![
+                Span::styled(
+                    format!(" Advent of Code {} ", self.year),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(format!(
+                    "| {} input",
+                    if self.sample { "sample" } else { "real" }
+                )),
+            ])),
+            header,
+        );
+        frame.render_widget(
+            Paragraph::new(
+                " arrows/hjkl move | r re-run day | a re-run all | s sample/real | v visualize | q quit",
+            )
+            .style(Style::default().fg(Color::DarkGray)),
+            footer,
+        );
+
+        let [calendar_area, side] = Layout::horizontal([
+            Constraint::Length(GRID_COLUMNS as u16 * 8 + 2),
+            Constraint::Min(0),
+        ])
+        .areas(body);
+        self.draw_calendar(frame, calendar_area);
+
+        if self.show_visualization {
+            let [details, visualization] =
+                Layout::vertical([Constraint::Length(9), Constraint::Min(0)]).areas(side);
+            self.draw_details(frame, details);
+            self.draw_visualization(frame, visualization);
+        } else {
+            self.draw_details(frame, side);
+        }
+    }
+
+    /// The grid of days, each coloured by status
+    fn draw_calendar(&self, frame: &mut Frame, area: Rect) {
+        let mut lines = vec![Line::raw("")];
+        for (row_idx, days) in self.days.chunks(GRID_COLUMNS).enumerate() {
+            let spans = days
+                .iter()
+                .enumerate()
+                .map(|(col, info)| {
+                    let mut style = Style::default().fg(self.status(info).color());
+                    if row_idx * GRID_COLUMNS + col == self.selected {
+                        style = style.add_modifier(Modifier::REVERSED | Modifier::BOLD);
+                    }
+                    Span::styled(format!("  {:>2}  ", info.day), style)
+                })
+                .flat_map(|span| [span, Span::raw("  ")])
+                .collect::<Vec<_>>();
+            lines.push(Line::from(spans));
+            lines.push(Line::raw(""));
+        }
+
+        for status in [
+            Status::Solved,
+            Status::Unverified,
+            Status::Failing,
+            Status::NotRun,
+            Status::Locked,
+        ] {
+            lines.push(Line::styled(
+                format!(" ■ {}", status.label()),
+                Style::default().fg(status.color()),
+            ));
+        }
+
+        frame.render_widget(
+            Paragraph::new(lines).block(Block::bordered().title(" Calendar ")),
+            area,
+        );
+    }
+
+    /// Answers, expected answers and timings for the selected day
+    fn draw_details(&self, frame: &mut Frame, area: Rect) {
+        let info = self.selected_day();
+        let status = self.status(info);
+        let mut lines = vec![
+            Line::from(vec![
+                Span::styled(
+                    format!("Day {}: {}", info.day, info.title.unwrap_or("?")),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!("  [{}]", status.label()),
+                    Style::default().fg(status.color()),
+                ),
+            ]),
+            Line::raw(""),
+        ];
+
+        match status {
+            Status::Locked => lines.push(Line::raw(format!(
+                "Unlocks {}",
+                calendar::unlock_label(info.year, info.day)
+            ))),
+            Status::NotImplemented => lines.push(Line::raw("No solver registered for this day")),
+            Status::NotRun => lines.push(Line::raw("Press r to run")),
+            _ => {}
+        }
+
+        for result in self.results.get(&info.day).into_iter().flatten() {
+            let (answer, color) = match &result.answer {
+                Ok(answer) => (answer.clone(), result.status().color()),
+                Err(e) => (format!("error: {}", e), Color::Red),
+            };
+            lines.push(Line::from(vec![
+                Span::raw(format!("Part {}: ", result.part)),
+                Span::styled(answer, Style::default().fg(color)),
+                Span::styled(
+                    format!("  ({:.2?})", result.elapsed),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ]));
+            lines.push(Line::styled(
+                match &result.expected {
+                    Some(expected) => format!("        expected {}", expected),
+                    None => "        no expected answer recorded".to_string(),
+                },
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+        if info.parts == 1 {
+            lines.push(Line::raw("Single-part day: there is no Part 2"));
+        }
+
+        frame.render_widget(
+            Paragraph::new(lines).block(Block::bordered().title(" Answers ")),
+            area,
+        );
+    }
+
+    /// A per-day picture of the input, where one exists
+    fn draw_visualization(&self, frame: &mut Frame, area: Rect) {
+        let day = self.selected_day().day;
+        let lines = match (self.year == y2025::YEAR, day) {
+            (true, 4) => visualize_day4(self.year, self.sample, self.params.threshold),
+            _ => vec![Line::raw(format!("No visualization for day {}", day))],
+        };
+
+        frame.render_widget(
+            Paragraph::new(lines).block(Block::bordered().title(" Visualization ")),
+            area,
+        );
+    }
+
+    /// Handles a key press
+    ///
+    /// # Returns
+    /// `false` when the dashboard should close
+    fn handle_key(&mut self, key: KeyCode) -> bool {
+        let last = self.days.len() - 1;
+        match key {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Left | KeyCode::Char('h') => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Right | KeyCode::Char('l') => self.selected = (self.selected + 1).min(last),
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.selected = self.selected.saturating_sub(GRID_COLUMNS)
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected = (self.selected + GRID_COLUMNS).min(last)
+            }
+            KeyCode::Char('r') | KeyCode::Enter => self.run_day(self.selected),
+            KeyCode::Char('a') => self.run_all(),
+            KeyCode::Char('s') => {
+                self.sample = !self.sample;
+                self.results.clear();
+                self.run_all();
+            }
+            KeyCode::Char('v') => self.show_visualization = !self.show_visualization,
+            _ => {}
+        }
+        true
+    }
+}
+
+/// Day 4's grid with rolls accessible under `threshold` highlighted
+fn visualize_day4(year: u16, sample: bool, threshold: usize) -> Vec<Line<'static>> {
+    let map = read_input(year, 4, 1, sample)
+        .and_then(|input| day04::accessibility_map(&input, threshold));
+    let map = match map {
+        Ok(map) => map,
+        Err(e) => return vec![Line::styled(e.to_string(), Style::default().fg(Color::Red))],
+    };
+
+    let accessible = map
+        .iter()
+        .flatten()
+        .filter(|&&cell| cell == Some(true))
+        .count();
+    let mut lines = vec![Line::raw(format!(
+        "{} accessible rolls (green) of {}",
+        accessible,
+        map.iter().flatten().filter(|cell| cell.is_some()).count()
+    ))];
+    lines.extend(map.iter().map(|row| {
+        Line::from(
+            row.iter()
+                .map(|cell| match cell {
+                    Some(true) => Span::styled("@", Style::default().fg(Color::Green)),
+                    Some(false) => Span::styled("@", Style::default().fg(Color::DarkGray)),
+                    None => Span::raw("."),
+                })
+                .collect::<Vec<_>>(),
+        )
+    }));
+    lines
+}
+
+/// Runs the dashboard until the user quits
+///
+/// Every implemented day is solved once on start-up; afterwards days are
+/// only re-run on request.
+///
+/// # Arguments
+/// * `year` - The event year to show
+/// * `sample` - Start on the sample inputs instead of the real ones
+/// * `focus` - Start with this day selected and its visualization open
+/// * `params` - The `[dayN]` parameters the visualizations use
+///
+/// # Errors
+/// Returns `AocError::InvalidYear` or `AocError::InvalidDay` for a year or
+/// day outside the calendar, or `AocError::IoError` if the terminal can't
+/// be driven
+pub fn run(year: u16, sample: bool, focus: Option<u8>, params: SolverParams) -> Result<()> {
+    let mut app = App::new(year, sample, focus, params)?;
+    let mut terminal = ratatui::init();
+    let result = event_loop(&mut terminal, &mut app);
+    ratatui::restore();
+    result
+}
+
+fn event_loop(terminal: &mut DefaultTerminal, app: &mut App) -> Result<()> {
+    terminal.draw(|frame| app.draw(frame))?;
+    app.run_all();
+
+    loop {
+        terminal.draw(|frame| app.draw(frame))?;
+        if let Event::Key(key) = event::read()? {
+            if key.kind == KeyEventKind::Press && !app.handle_key(key.code) {
+                return Ok(());
+            }
+        }
+    }
+}
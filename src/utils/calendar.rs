@@ -0,0 +1,157 @@
+use crate::utils::error::{AocError, Result};
+use std::ops::RangeInclusive;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// The event year used when none is given
+pub const DEFAULT_YEAR: u16 = 2025;
+
+/// The first Advent of Code event
+pub const FIRST_YEAR: u16 = 2015;
+
+/// Returns the puzzle days of an event year
+///
+/// Events from 2015 to 2024 ran for 25 days; from 2025 on they have 12.
+/// Whether a day has actually unlocked yet is up to `check_released`.
+///
+/// # Returns
+/// `None` for years before the first event
+pub fn valid_days(year: u16) -> Option<RangeInclusive<u8>> {
+    match year {
+        FIRST_YEAR..=2024 => Some(1..=25),
+        2025.. => Some(1..=12),
+        _ => None,
+    }
+}
+
+/// Checks that `day` is a puzzle day of `year`
+///
+/// # Errors
+/// Returns `AocError::InvalidYear` if there is no event for the year
+/// Returns `AocError::InvalidDay` if the day is outside the event
+pub fn check_day(year: u16, day: u8) -> Result<()> {
+    let days = valid_days(year).ok_or(AocError::InvalidYear(year))?;
+    if !days.contains(&day) {
+        return Err(AocError::InvalidDay(year, day));
+    }
+    Ok(())
+}
+
+/// Returns how many puzzle parts a day has
+///
+/// The last day of every event has a single puzzle; its second star is
+/// awarded for finishing all the others.
+///
+/// # Errors
+/// Same as `check_day`
+pub fn parts_in_day(year: u16, day: u8) -> Result<u8> {
+    check_day(year, day)?;
+    let last = valid_days(year).map_or(0, |days| *days.end());
+    Ok(if day == last { 1 } else { 2 })
+}
+
+/// Checks that `part` is a puzzle part of the given day
+///
+/// # Errors
+/// Returns the errors of `check_day`
+/// Returns `AocError::ParseError` if part is not 1 or 2
+/// Returns `AocError::SinglePartDay` for part 2 of a day with one part
+pub fn check_part(year: u16, day: u8, part: u8) -> Result<()> {
+    let parts = parts_in_day(year, day)?;
+    if part != 1 && part != 2 {
+        return Err(AocError::ParseError(format!(
+            "Invalid part number: {}. Part must be 1 or 2.",
+            part
+        )));
+    }
+    if part > parts {
+        return Err(AocError::SinglePartDay(year, day));
+    }
+    Ok(())
+}
+
+/// Returns when a day's puzzle unlocks
+///
+/// Puzzles unlock at midnight US Eastern time (05:00 UTC) on December `day`.
+///
+/// # Errors
+/// Same as `check_day`
+pub fn unlock_time(year: u16, day: u8) -> Result<SystemTime> {
+    check_day(year, day)?;
+
+    // Days from 1970-01-01 to January 1st of `year` (proleptic Gregorian)
+    let y = u64::from(year) - 1;
+    let year_start = y * 365 + y / 4 - y / 100 + y / 400 - 719_162;
+    let leap = u64::from(year % 4 == 0 && (year % 100 != 0 || year % 400 == 0));
+    let december_1st = year_start + 334 + leap;
+
+    let secs = (december_1st + u64::from(day) - 1) * 86_400 + 5 * 3_600;
+    Ok(UNIX_EPOCH + Duration::from_secs(secs))
+}
+
+/// Formats a day's unlock time, e.g. `2025-12-01 05:00 UTC`
+pub fn unlock_label(year: u16, day: u8) -> String {
+    format!("{}-12-{:02} 05:00 UTC", year, day)
+}
+
+/// Checks whether a day's puzzle has unlocked
+///
+/// # Errors
+/// Returns the errors of `check_day`
+/// Returns `AocError::NotReleased` if the puzzle is still locked
+pub fn check_released(year: u16, day: u8) -> Result<()> {
+    if SystemTime::now() < unlock_time(year, day)? {
+        return Err(AocError::NotReleased(year, day, unlock_label(year, day)));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unix_secs(year: u16, day: u8) -> u64 {
+        let time = unlock_time(year, day).unwrap();
+        time.duration_since(UNIX_EPOCH).unwrap().as_secs()
+    }
+
+    #[test]
+    fn unlocks_at_midnight_eastern() {
+        // 2025-12-01T05:00:00Z
+        assert_eq!(unix_secs(2025, 1), 1_764_565_200);
+        // 2015-12-01T05:00:00Z, the first puzzle
+        assert_eq!(unix_secs(2015, 1), 1_448_946_000);
+    }
+
+    #[test]
+    fn accounts_for_leap_years() {
+        // 2016 and 2024 are leap years, 2023 isn't
+        assert_eq!(unix_secs(2016, 1), 1_480_568_400);
+        assert_eq!(unix_secs(2023, 25), 1_703_480_400);
+        assert_eq!(unix_secs(2024, 25), 1_735_102_800);
+    }
+
+    #[test]
+    fn rejects_days_outside_the_event() {
+        assert!(matches!(
+            unlock_time(2025, 13),
+            Err(AocError::InvalidDay(2025, 13))
+        ));
+        assert!(matches!(
+            unlock_time(2014, 1),
+            Err(AocError::InvalidYear(2014))
+        ));
+    }
+
+    #[test]
+    fn event_length_follows_the_calendar() {
+        assert_eq!(valid_days(FIRST_YEAR - 1), None);
+        assert_eq!(valid_days(2024), Some(1..=25));
+        assert_eq!(valid_days(2025), Some(1..=12));
+        // Later events are known without bumping `DEFAULT_YEAR`
+        assert_eq!(valid_days(2026), Some(1..=12));
+        assert!(matches!(
+            check_released(2999, 1),
+            Err(AocError::NotReleased(2999, 1, _))
+        ));
+    }
+}
@@ -0,0 +1,95 @@
+use crate::utils::error::{AocError, Result};
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+
+/// A flag shared between a running solver and whoever started it
+///
+/// The runner cancels the token when a part's `--timeout` fires. Solvers
+/// don't see the token directly: they call `checkpoint` in their hot loops,
+/// which checks the token installed on the current thread.
+#[derive(Debug, Clone)]
+pub struct CancelToken {
+    cancelled: Arc<AtomicBool>,
+    started: Instant,
+}
+
+impl CancelToken {
+    /// Creates a token that hasn't been cancelled
+    pub fn new() -> Self {
+        CancelToken {
+            cancelled: Arc::new(AtomicBool::new(false)),
+            started: Instant::now(),
+        }
+    }
+
+    /// Asks the solver polling this token to stop
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Whether `cancel` has been called on this token or any clone of it
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    /// Makes this the token `checkpoint` checks on the current thread
+    pub fn install(&self) {
+        CURRENT.with(|current| *current.borrow_mut() = Some(self.clone()));
+    }
+}
+
+impl Default for CancelToken {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<CancelToken>> = const { RefCell::new(None) };
+}
+
+/// Returns early from a solver whose run has been cancelled
+///
+/// Cheap enough to call once per iteration of a hot loop. Without an
+/// installed token (e.g. in the REPL or in tests) it always succeeds.
+///
+/// # Errors
+/// Returns `AocError::Timeout` with the time since the token was created
+/// once the current thread's token is cancelled
+pub fn checkpoint() -> Result<()> {
+    CURRENT.with(|current| match &*current.borrow() {
+        Some(token) if token.is_cancelled() => Err(AocError::Timeout(token.started.elapsed())),
+        _ => Ok(()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checkpoint_fails_once_the_installed_token_is_cancelled() {
+        assert!(checkpoint().is_ok(), "no token installed yet");
+
+        let token = CancelToken::new();
+        token.install();
+        assert!(checkpoint().is_ok());
+
+        token.clone().cancel();
+        assert!(token.is_cancelled());
+        assert!(matches!(checkpoint(), Err(AocError::Timeout(_))));
+    }
+
+    #[test]
+    fn tokens_are_per_thread() {
+        let token = CancelToken::new();
+        token.install();
+        token.cancel();
+
+        let other = std::thread::spawn(checkpoint).join().unwrap();
+        assert!(other.is_ok());
+        assert!(checkpoint().is_err());
+    }
+}
@@ -0,0 +1,147 @@
+use crate::utils::error::{AocError, Result};
+use age::secrecy::ExposeSecret;
+use age::x25519::Identity;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Environment variable holding the age secret key (`AGE-SECRET-KEY-1…`)
+pub const KEY_ENV: &str = "AOC_INPUT_KEY";
+
+/// Environment variable pointing at an age identity file
+pub const KEYFILE_ENV: &str = "AOC_INPUT_KEYFILE";
+
+/// Identity file used when neither environment variable is set
+pub const DEFAULT_KEYFILE: &str = ".aoc/input.key";
+
+/// Returns the encrypted counterpart of an input path (`day01p1.txt` -> `day01p1.txt.age`)
+pub fn encrypted_path(path: &Path) -> PathBuf {
+    let mut encrypted = path.as_os_str().to_owned();
+    encrypted.push(".age");
+    PathBuf::from(encrypted)
+}
+
+/// Loads the age identity used to encrypt and decrypt inputs
+///
+/// Looked up, in order, from the `AOC_INPUT_KEY` environment variable,
+/// the file named by `AOC_INPUT_KEYFILE`, and `.aoc/input.key`. Key files
+/// use the `age-keygen` format, so `#` comment lines are ignored.
+///
+/// # Errors
+/// Returns `AocError::CryptoError` if no key is configured or it is invalid
+pub fn load_identity() -> Result<Identity> {
+    if let Ok(key) = std::env::var(KEY_ENV) {
+        return parse_identity(&key, KEY_ENV);
+    }
+
+    let keyfile = std::env::var(KEYFILE_ENV).unwrap_or_else(|_| DEFAULT_KEYFILE.to_string());
+    match fs::read_to_string(&keyfile) {
+        Ok(contents) => parse_identity(&contents, &keyfile),
+        Err(_) => Err(AocError::CryptoError(format!(
+            "No input key found: set {} or {}, or create {} (e.g. with `aoc-2025 encrypt --generate-key`)",
+            KEY_ENV, KEYFILE_ENV, DEFAULT_KEYFILE
+        ))),
+    }
+}
+
+/// Parses the first non-comment line of `text` as an age X25519 identity
+fn parse_identity(text: &str, source: &str) -> Result<Identity> {
+    text.lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty() && !line.starts_with('#'))
+        .ok_or_else(|| AocError::CryptoError(format!("No key found in {}", source)))?
+        .parse()
+        .map_err(|e| AocError::CryptoError(format!("Invalid key in {}: {}", source, e)))
+}
+
+/// Creates a new identity file at `path`
+///
+/// # Returns
+/// The public recipient string, which can be shared freely
+///
+/// # Errors
+/// Returns `AocError::IoError` if the file already exists or can't be written
+pub fn generate_keyfile(path: &Path) -> Result<String> {
+    if path.exists() {
+        return Err(AocError::IoError(std::io::Error::new(
+            std::io::ErrorKind::AlreadyExists,
+            format!("{} already exists", path.display()),
+        )));
+    }
+
+    let identity = Identity::generate();
+    let recipient = identity.to_public().to_string();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(
+        path,
+        format!(
+            "# public key: {}\n{}\n",
+            recipient,
+            identity.to_string().expose_secret()
+        ),
+    )?;
+
+    Ok(recipient)
+}
+
+/// Encrypts `plaintext` to the identity's public key
+///
+/// # Errors
+/// Returns `AocError::CryptoError` if encryption fails
+pub fn encrypt(identity: &Identity, plaintext: &str) -> Result<Vec<u8>> {
+    age::encrypt(&identity.to_public(), plaintext.as_bytes())
+        .map_err(|e| AocError::CryptoError(format!("Encryption failed: {}", e)))
+}
+
+/// Decrypts an age-encrypted input file
+///
+/// # Errors
+/// Returns `AocError::IoError` if the file can't be read, or
+/// `AocError::CryptoError` if the key is missing or wrong
+pub fn decrypt_file(path: &Path, identity: &Identity) -> Result<String> {
+    let ciphertext = fs::read(path)?;
+    let plaintext = age::decrypt(identity, &ciphertext).map_err(|e| {
+        AocError::CryptoError(format!("Could not decrypt {}: {}", path.display(), e))
+    })?;
+
+    String::from_utf8(plaintext).map_err(|_| {
+        AocError::CryptoError(format!("{} does not decrypt to UTF-8 text", path.display()))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encrypted_files_round_trip() {
+        let identity = Identity::generate();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("day01p1.txt.age");
+        fs::write(&path, encrypt(&identity, "L68\nR48\n").unwrap()).unwrap();
+
+        assert_eq!(decrypt_file(&path, &identity).unwrap(), "L68\nR48\n");
+    }
+
+    #[test]
+    fn wrong_key_is_a_crypto_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("day01p1.txt.age");
+        fs::write(&path, encrypt(&Identity::generate(), "secret").unwrap()).unwrap();
+
+        let err = decrypt_file(&path, &Identity::generate()).unwrap_err();
+        assert!(matches!(err, AocError::CryptoError(_)), "{err:?}");
+    }
+
+    #[test]
+    fn generated_keyfile_parses_back() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("input.key");
+        let recipient = generate_keyfile(&path).unwrap();
+
+        let identity = parse_identity(&fs::read_to_string(&path).unwrap(), "test").unwrap();
+        assert_eq!(identity.to_public().to_string(), recipient);
+        assert!(generate_keyfile(&path).is_err(), "an existing key is kept");
+    }
+}
@@ -1,10 +1,22 @@
+use std::fmt;
+use std::ops::Range;
+use std::time::Duration;
 use thiserror::Error;
 
 /// Custom error type for Advent of Code solutions
 #[derive(Error, Debug)]
 pub enum AocError {
-    #[error("Invalid day number: {0}. Day must be between 1 and 25.")]
-    InvalidDay(u8),
+    #[error("Invalid year: {0}. There is no Advent of Code event for that year.")]
+    InvalidYear(u16),
+
+    #[error("Invalid day number: {1}. Advent of Code {0} has no such day.")]
+    InvalidDay(u16, u8),
+
+    #[error("Day {1} of Advent of Code {0} is not released yet (it unlocks {2}).")]
+    NotReleased(u16, u8, String),
+
+    #[error("Day {1} of Advent of Code {0} is a single-part day; there is no part 2.")]
+    SinglePartDay(u16, u8),
 
     #[error("Input file not found for day {0}: {1}")]
     InputNotFound(u8, String),
@@ -15,10 +27,439 @@ pub enum AocError {
     #[error("Parse error: {0}")]
     ParseError(String),
 
+    #[error("Parse error: {0}")]
+    Diagnostic(Box<ParseDiagnostic>),
+
     #[error("Solution error: {0}")]
     SolutionError(String),
+
+    #[error("Solver panicked at {1}: {0}")]
+    SolverPanic(String, String),
+
+    #[error("Timed out after {0:.2?}")]
+    Timeout(Duration),
+
+    #[error("Advent of Code request failed: {0}")]
+    RequestError(String),
+
+    #[error("Wrong answer: {0}")]
+    WrongAnswer(String),
+
+    #[error("Encrypted input error: {0}")]
+    CryptoError(String),
+
+    #[error("{0} already exists (pass --force to overwrite it)")]
+    AlreadyExists(String),
+}
+
+/// Process exit codes reported by the `aoc-2025` binary
+///
+/// These values are stable so scripts can tell failure kinds apart.
+pub mod exit_code {
+    /// Everything succeeded
+    pub const SUCCESS: u8 = 0;
+    /// An error that doesn't map to a more specific code
+    pub const FAILURE: u8 = 1;
+    /// Invalid command-line usage (reported by the argument parser)
+    pub const USAGE: u8 = 2;
+    /// `AocError::InvalidYear` / `AocError::InvalidDay` / `AocError::NotReleased` /
+    /// `AocError::SinglePartDay`: the year, day or part is outside the event
+    /// calendar, or the puzzle hasn't unlocked yet
+    pub const INVALID_DAY: u8 = 3;
+    /// `AocError::InputNotFound`: the input file doesn't exist
+    pub const INPUT_NOT_FOUND: u8 = 4;
+    /// `AocError::IoError`: the input exists but couldn't be read
+    pub const IO_ERROR: u8 = 5;
+    /// `AocError::ParseError` / `AocError::Diagnostic`: the input is malformed
+    pub const PARSE_ERROR: u8 = 6;
+    /// `AocError::SolutionError` / `AocError::SolverPanic`: the solver failed
+    /// or panicked, or the day or part is not implemented
+    pub const SOLUTION_ERROR: u8 = 7;
+    /// `AocError::WrongAnswer`: a computed answer didn't match the expected answer
+    pub const VERIFICATION_MISMATCH: u8 = 8;
+    /// `AocError::RequestError`: talking to the Advent of Code server failed
+    pub const REQUEST_ERROR: u8 = 9;
+    /// `AocError::CryptoError`: an encrypted input couldn't be decrypted (or encrypted)
+    pub const CRYPTO_ERROR: u8 = 10;
+    /// `AocError::Timeout`: a solver ran past `--timeout`
+    pub const TIMEOUT: u8 = 11;
+    /// `AocError::AlreadyExists`: a file would be overwritten without `--force`
+    pub const ALREADY_EXISTS: u8 = 12;
+}
+
+impl AocError {
+    /// The process exit code for this error (see `exit_code`)
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            AocError::InvalidYear(_)
+            | AocError::InvalidDay(_, _)
+            | AocError::NotReleased(_, _, _)
+            | AocError::SinglePartDay(_, _) => exit_code::INVALID_DAY,
+            AocError::InputNotFound(_, _) => exit_code::INPUT_NOT_FOUND,
+            AocError::IoError(_) => exit_code::IO_ERROR,
+            AocError::ParseError(_) | AocError::Diagnostic(_) => exit_code::PARSE_ERROR,
+            AocError::SolutionError(_) | AocError::SolverPanic(_, _) => exit_code::SOLUTION_ERROR,
+            AocError::RequestError(_) => exit_code::REQUEST_ERROR,
+            AocError::WrongAnswer(_) => exit_code::VERIFICATION_MISMATCH,
+            AocError::CryptoError(_) => exit_code::CRYPTO_ERROR,
+            AocError::Timeout(_) => exit_code::TIMEOUT,
+            AocError::AlreadyExists(_) => exit_code::ALREADY_EXISTS,
+        }
+    }
+
+    /// Attaches the source file path to a located parse error
+    ///
+    /// Other variants are returned unchanged.
+    pub fn with_path(self, path: impl Into<String>) -> Self {
+        match self {
+            AocError::Diagnostic(mut diagnostic) => {
+                diagnostic.path = Some(path.into());
+                AocError::Diagnostic(diagnostic)
+            }
+            other => other,
+        }
+    }
+}
+
+impl From<ParseDiagnostic> for AocError {
+    fn from(diagnostic: ParseDiagnostic) -> Self {
+        AocError::Diagnostic(Box::new(diagnostic))
+    }
+}
+
+/// A parse error pinned to a location in the input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDiagnostic {
+    /// Path of the input file, once known
+    pub path: Option<String>,
+    /// 1-based line number
+    pub line: usize,
+    /// 1-based column, counted in characters
+    pub column: usize,
+    /// Byte span of the offending text within the input
+    pub span: Range<usize>,
+    /// What went wrong
+    pub message: String,
+    /// Description of the token that was expected at the span
+    pub expected: String,
+}
+
+impl ParseDiagnostic {
+    /// Creates a diagnostic for `token`, which must be a subslice of `source`
+    ///
+    /// This is the usual case for solvers that slice lines and tokens
+    /// straight out of the input (including via `trim`). The position comes
+    /// from where `token` points into `source`, so repeated text is located
+    /// correctly. A `token` that doesn't borrow from `source` is a bug in the
+    /// caller: it panics in debug builds and points at the start otherwise.
+    ///
+    /// # Arguments
+    /// * `source` - The full input text
+    /// * `token` - The offending text, borrowed from `source`
+    /// * `message` - What went wrong
+    /// * `expected` - What should have been there instead
+    pub fn at_token(
+        source: &str,
+        token: &str,
+        message: impl Into<String>,
+        expected: impl Into<String>,
+    ) -> Self {
+        let span = match subslice_offset(source, token) {
+            Some(start) => start..start + token.len(),
+            None => {
+                debug_assert!(
+                    false,
+                    "ParseDiagnostic::at_token: token does not borrow from source"
+                );
+                0..0
+            }
+        };
+        Self::from_span(source, span, message, expected)
+    }
+
+    /// Creates a diagnostic for `token` inside a copy of line `line_no`
+    ///
+    /// Use this when the line no longer borrows from `source`, e.g. after
+    /// `utils::sections` normalized it. `token` must be a subslice of `line`.
+    /// `line` may have been trimmed: it is located within line `line_no` of
+    /// `source`, so columns still count any leading whitespace.
+    ///
+    /// # Arguments
+    /// * `source` - The full input text
+    /// * `line_no` - 1-based number of the line in `source`
+    /// * `line` - The text of that line
+    /// * `token` - The offending text, borrowed from `line`
+    /// * `message` - What went wrong
+    /// * `expected` - What should have been there instead
+    pub fn at_line(
+        source: &str,
+        line_no: usize,
+        line: &str,
+        token: &str,
+        message: impl Into<String>,
+        expected: impl Into<String>,
+    ) -> Self {
+        // Where `line` (possibly trimmed) begins within the original line
+        let (line_start, raw_line) = nth_line(source, line_no);
+        let line_offset = subslice_offset(raw_line, line)
+            .or_else(|| raw_line.find(line))
+            .unwrap_or(0);
+        let in_line = subslice_offset(line, token).unwrap_or(0);
+        let start = (line_start + line_offset + in_line).min(source.len());
+        let end = (start + token.len()).min(source.len());
+        Self::from_span(source, start..end, message, expected)
+    }
+
+    /// Creates a diagnostic for a byte span of `source`
+    ///
+    /// A span past the end of `source` or inside a multi-byte character is
+    /// moved back to the nearest character boundary.
+    pub fn from_span(
+        source: &str,
+        span: Range<usize>,
+        message: impl Into<String>,
+        expected: impl Into<String>,
+    ) -> Self {
+        let start = floor_char_boundary(source, span.start);
+        let end = floor_char_boundary(source, span.end.max(start));
+        let span = start..end;
+        let before = &source[..span.start];
+        let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+
+        ParseDiagnostic {
+            path: None,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            span,
+            message: message.into(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Renders the diagnostic as a source snippet with a caret marker
+    ///
+    /// ```text
+    /// error: Invalid range start
+    ///  --> inputs/day05p1.txt:3:1
+    ///   |
+    /// 3 | 3x-5
+    ///   | ^^ expected an unsigned integer
+    /// ```
+    pub fn render(&self, source: &str) -> String {
+        let (_, line_text) = nth_line(source, self.line);
+        let gutter = self.line.to_string().len();
+        let location = self.path.as_deref().unwrap_or("<input>");
+
+        // Underline at least one character, and never past the end of the line
+        let line_chars = line_text.chars().count();
+        let token_chars = source
+            .get(self.span.clone())
+            .map_or(1, |token| token.chars().count())
+            .max(1)
+            .min((line_chars + 1).saturating_sub(self.column).max(1));
+
+        format!(
+            "error: {message}\n\
+             {pad}--> {location}:{line}:{column}\n\
+             {pad} |\n\
+             {line:>gutter$} | {line_text}\n\
+             {pad} | {indent}{carets} expected {expected}",
+            message = self.message,
+            pad = " ".repeat(gutter),
+            location = location,
+            line = self.line,
+            column = self.column,
+            gutter = gutter,
+            line_text = line_text,
+            indent = " ".repeat(self.column.saturating_sub(1)),
+            carets = "^".repeat(token_chars),
+            expected = self.expected,
+        )
+    }
+}
+
+impl fmt::Display for ParseDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(path) = &self.path {
+            write!(f, "{}:", path)?;
+        }
+        write!(
+            f,
+            "{}:{}: {} (expected {})",
+            self.line, self.column, self.message, self.expected
+        )
+    }
+}
+
+/// The byte offset and text of 1-based line `line_no` of `source`
+///
+/// Lines end at `\n`, and the `\r` of a `\r\n` ending isn't part of the
+/// text, the same way `str::lines` (and so `utils::sections`) counts them.
+/// A line past the end is empty and starts at the end of `source`.
+fn nth_line(source: &str, line_no: usize) -> (usize, &str) {
+    let mut start = 0;
+    for (idx, line) in source.split('\n').enumerate() {
+        if idx + 1 == line_no.max(1) {
+            return (start, line.strip_suffix('\r').unwrap_or(line));
+        }
+        start += line.len() + 1;
+    }
+    (source.len(), "")
+}
+
+/// The largest character boundary of `s` at or before `index`
+fn floor_char_boundary(s: &str, index: usize) -> usize {
+    let mut index = index.min(s.len());
+    while !s.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+/// Byte offset of `inner` within `outer`, if `inner` borrows from it
+fn subslice_offset(outer: &str, inner: &str) -> Option<usize> {
+    let outer_start = outer.as_ptr() as usize;
+    let inner_start = inner.as_ptr() as usize;
+
+    (inner_start >= outer_start && inner_start + inner.len() <= outer_start + outer.len())
+        .then(|| inner_start - outer_start)
 }
 
 /// Result type alias using AocError
 pub type Result<T> = std::result::Result<T, AocError>;
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_variant_has_its_exit_code() {
+        let source = "x\n";
+        let diagnostic = ParseDiagnostic::at_token(source, &source[..1], "Invalid", "a number");
+        let cases = [
+            (AocError::InvalidYear(2014), exit_code::INVALID_DAY),
+            (AocError::InvalidDay(2025, 13), exit_code::INVALID_DAY),
+            (
+                AocError::NotReleased(2025, 12, "soon".to_string()),
+                exit_code::INVALID_DAY,
+            ),
+            (AocError::SinglePartDay(2025, 12), exit_code::INVALID_DAY),
+            (
+                AocError::InputNotFound(1, "inputs/2025/day01p1.txt".to_string()),
+                exit_code::INPUT_NOT_FOUND,
+            ),
+            (
+                AocError::IoError(std::io::Error::other("disk")),
+                exit_code::IO_ERROR,
+            ),
+            (
+                AocError::ParseError("bad".to_string()),
+                exit_code::PARSE_ERROR,
+            ),
+            (AocError::from(diagnostic), exit_code::PARSE_ERROR),
+            (
+                AocError::SolutionError("none".to_string()),
+                exit_code::SOLUTION_ERROR,
+            ),
+            (
+                AocError::SolverPanic("boom".to_string(), "day01.rs:1:1".to_string()),
+                exit_code::SOLUTION_ERROR,
+            ),
+            (
+                AocError::Timeout(Duration::from_secs(1)),
+                exit_code::TIMEOUT,
+            ),
+            (
+                AocError::RequestError("offline".to_string()),
+                exit_code::REQUEST_ERROR,
+            ),
+            (
+                AocError::WrongAnswer("got 1".to_string()),
+                exit_code::VERIFICATION_MISMATCH,
+            ),
+            (
+                AocError::CryptoError("no key".to_string()),
+                exit_code::CRYPTO_ERROR,
+            ),
+            (
+                AocError::AlreadyExists("src/days/y2025/day06.rs".to_string()),
+                exit_code::ALREADY_EXISTS,
+            ),
+        ];
+        for (error, code) in cases {
+            assert_eq!(error.exit_code(), code, "{:?}", error);
+        }
+        assert_eq!(exit_code::INVALID_DAY, 3);
+        assert_eq!(exit_code::ALREADY_EXISTS, 12);
+    }
+
+    #[test]
+    fn exit_codes_are_distinct() {
+        let mut codes = [
+            exit_code::SUCCESS,
+            exit_code::FAILURE,
+            exit_code::USAGE,
+            exit_code::INVALID_DAY,
+            exit_code::INPUT_NOT_FOUND,
+            exit_code::IO_ERROR,
+            exit_code::PARSE_ERROR,
+            exit_code::SOLUTION_ERROR,
+            exit_code::VERIFICATION_MISMATCH,
+            exit_code::REQUEST_ERROR,
+            exit_code::CRYPTO_ERROR,
+            exit_code::TIMEOUT,
+            exit_code::ALREADY_EXISTS,
+        ];
+        codes.sort_unstable();
+        assert_eq!(codes.to_vec(), (0..=12).collect::<Vec<u8>>());
+    }
+
+    #[test]
+    fn at_token_locates_the_borrowed_occurrence() {
+        let source = "12-x\n34-x\n";
+        let second_x = &source[8..9];
+        let diagnostic = ParseDiagnostic::at_token(source, second_x, "Invalid end", "a number");
+        assert_eq!((diagnostic.line, diagnostic.column), (2, 4));
+        assert_eq!(diagnostic.span, 8..9);
+    }
+
+    #[test]
+    fn at_line_counts_leading_whitespace() {
+        let source = "1-2\n   7-x\n";
+        let line = "7-x".to_string();
+        let diagnostic =
+            ParseDiagnostic::at_line(source, 2, &line, &line[2..], "Invalid end", "a number");
+        assert_eq!((diagnostic.line, diagnostic.column), (2, 6));
+        assert_eq!(&source[diagnostic.span.clone()], "x");
+    }
+
+    #[test]
+    fn from_span_clamps_to_char_boundaries() {
+        let source = "a\u{e9}b";
+        // Byte 2 is inside the two-byte `\u{e9}`, so the span widens to cover it
+        let diagnostic = ParseDiagnostic::from_span(source, 2..3, "Invalid", "a letter");
+        assert_eq!(diagnostic.span, 1..3);
+        assert_eq!(diagnostic.column, 2);
+
+        let past_end = ParseDiagnostic::from_span(source, 10..12, "Invalid", "a letter");
+        assert_eq!(past_end.span, 4..4);
+    }
+
+    #[test]
+    fn renders_hand_built_positions_without_panicking() {
+        // The fields are public, so a caller can build a 0 line or column
+        let diagnostic = ParseDiagnostic {
+            path: None,
+            line: 0,
+            column: 0,
+            span: 0..1,
+            message: "Invalid".to_string(),
+            expected: "a number".to_string(),
+        };
+        let rendered = diagnostic.render("x1\n");
+        assert!(
+            rendered.contains("0 | x1\n  | ^ expected a number"),
+            "{rendered}"
+        );
+    }
+}
@@ -0,0 +1,404 @@
+use crate::utils::error::{AocError, Result};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+/// A point (or offset) on a 2D integer plane
+///
+/// Grid conventions: `x` is the column and `y` is the row, with `y`
+/// growing downwards, so `Dir4::Up` is `(0, -1)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point2 {
+    /// The origin `(0, 0)`
+    pub const ORIGIN: Point2 = Point2 { x: 0, y: 0 };
+
+    /// Creates a new point from its `x` and `y` coordinates
+    pub const fn new(x: i64, y: i64) -> Self {
+        Point2 { x, y }
+    }
+
+    /// Creates a point from grid indices (`row` becomes `y`, `col` becomes `x`)
+    pub fn from_grid(row: usize, col: usize) -> Self {
+        Point2::new(col as i64, row as i64)
+    }
+
+    /// Converts the point into `(row, col)` grid indices
+    ///
+    /// # Returns
+    /// `None` if the point lies outside a grid of `rows` x `cols`
+    pub fn to_grid(self, rows: usize, cols: usize) -> Option<(usize, usize)> {
+        let row = usize::try_from(self.y).ok()?;
+        let col = usize::try_from(self.x).ok()?;
+        (row < rows && col < cols).then_some((row, col))
+    }
+
+    /// Manhattan (taxicab) distance to another point
+    pub fn manhattan(self, other: Point2) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Chebyshev (king-move) distance to another point
+    pub fn chebyshev(self, other: Point2) -> u64 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// Returns the neighbouring point one step in the given direction
+    pub fn step(self, dir: Dir4) -> Point2 {
+        self + dir.delta()
+    }
+
+    /// Returns the 4 orthogonal neighbours, in `Dir4::ALL` order
+    pub fn neighbors4(self) -> [Point2; 4] {
+        Dir4::ALL.map(|d| self + d.delta())
+    }
+
+    /// Returns the 8 surrounding neighbours, in `Dir8::ALL` order
+    pub fn neighbors8(self) -> [Point2; 8] {
+        Dir8::ALL.map(|d| self + d.delta())
+    }
+}
+
+impl Add for Point2 {
+    type Output = Point2;
+
+    fn add(self, rhs: Point2) -> Point2 {
+        Point2::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Point2 {
+    fn add_assign(&mut self, rhs: Point2) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point2 {
+    type Output = Point2;
+
+    fn sub(self, rhs: Point2) -> Point2 {
+        Point2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign for Point2 {
+    fn sub_assign(&mut self, rhs: Point2) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<i64> for Point2 {
+    type Output = Point2;
+
+    fn mul(self, rhs: i64) -> Point2 {
+        Point2::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl Neg for Point2 {
+    type Output = Point2;
+
+    fn neg(self) -> Point2 {
+        Point2::new(-self.x, -self.y)
+    }
+}
+
+/// A point (or offset) in 3D integer space
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point3 {
+    /// The origin `(0, 0, 0)`
+    pub const ORIGIN: Point3 = Point3 { x: 0, y: 0, z: 0 };
+
+    /// Creates a new point from its `x`, `y` and `z` coordinates
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Point3 { x, y, z }
+    }
+
+    /// Manhattan (taxicab) distance to another point
+    pub fn manhattan(self, other: Point3) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+    }
+
+    /// Chebyshev (king-move) distance to another point
+    pub fn chebyshev(self, other: Point3) -> u64 {
+        self.x
+            .abs_diff(other.x)
+            .max(self.y.abs_diff(other.y))
+            .max(self.z.abs_diff(other.z))
+    }
+}
+
+impl Add for Point3 {
+    type Output = Point3;
+
+    fn add(self, rhs: Point3) -> Point3 {
+        Point3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl AddAssign for Point3 {
+    fn add_assign(&mut self, rhs: Point3) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Point3;
+
+    fn sub(self, rhs: Point3) -> Point3 {
+        Point3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl SubAssign for Point3 {
+    fn sub_assign(&mut self, rhs: Point3) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<i64> for Point3 {
+    type Output = Point3;
+
+    fn mul(self, rhs: i64) -> Point3 {
+        Point3::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl Neg for Point3 {
+    type Output = Point3;
+
+    fn neg(self) -> Point3 {
+        Point3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+/// One of the 4 orthogonal directions on a grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir4 {
+    /// All directions, clockwise starting from `Up`
+    pub const ALL: [Dir4; 4] = [Dir4::Up, Dir4::Right, Dir4::Down, Dir4::Left];
+
+    /// Unit offset of this direction (`y` grows downwards)
+    pub fn delta(self) -> Point2 {
+        match self {
+            Dir4::Up => Point2::new(0, -1),
+            Dir4::Right => Point2::new(1, 0),
+            Dir4::Down => Point2::new(0, 1),
+            Dir4::Left => Point2::new(-1, 0),
+        }
+    }
+
+    /// Rotates 90 degrees clockwise
+    pub fn rotate_cw(self) -> Dir4 {
+        match self {
+            Dir4::Up => Dir4::Right,
+            Dir4::Right => Dir4::Down,
+            Dir4::Down => Dir4::Left,
+            Dir4::Left => Dir4::Up,
+        }
+    }
+
+    /// Rotates 90 degrees counter-clockwise
+    pub fn rotate_ccw(self) -> Dir4 {
+        match self {
+            Dir4::Up => Dir4::Left,
+            Dir4::Left => Dir4::Down,
+            Dir4::Down => Dir4::Right,
+            Dir4::Right => Dir4::Up,
+        }
+    }
+
+    /// Returns the direction pointing the other way
+    pub fn opposite(self) -> Dir4 {
+        match self {
+            Dir4::Up => Dir4::Down,
+            Dir4::Right => Dir4::Left,
+            Dir4::Down => Dir4::Up,
+            Dir4::Left => Dir4::Right,
+        }
+    }
+}
+
+impl TryFrom<char> for Dir4 {
+    type Error = AocError;
+
+    /// Parses `U`/`D`/`L`/`R` (upper case only) or `^`/`v`/`<`/`>`
+    fn try_from(c: char) -> Result<Self> {
+        match c {
+            'U' | '^' => Ok(Dir4::Up),
+            'R' | '>' => Ok(Dir4::Right),
+            'D' | 'v' => Ok(Dir4::Down),
+            'L' | '<' => Ok(Dir4::Left),
+            _ => Err(AocError::ParseError(format!("Invalid direction: '{}'", c))),
+        }
+    }
+}
+
+impl FromStr for Dir4 {
+    type Err = AocError;
+
+    /// Parses a single-character direction (see `Dir4::try_from`)
+    fn from_str(s: &str) -> Result<Self> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Dir4::try_from(c),
+            _ => Err(AocError::ParseError(format!("Invalid direction: '{}'", s))),
+        }
+    }
+}
+
+/// One of the 8 compass directions on a grid (orthogonal and diagonal)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Dir8 {
+    /// All directions, clockwise starting from `N`
+    pub const ALL: [Dir8; 8] = [
+        Dir8::N,
+        Dir8::NE,
+        Dir8::E,
+        Dir8::SE,
+        Dir8::S,
+        Dir8::SW,
+        Dir8::W,
+        Dir8::NW,
+    ];
+
+    /// Unit offset of this direction (`y` grows downwards, so `N` is `(0, -1)`)
+    pub fn delta(self) -> Point2 {
+        match self {
+            Dir8::N => Point2::new(0, -1),
+            Dir8::NE => Point2::new(1, -1),
+            Dir8::E => Point2::new(1, 0),
+            Dir8::SE => Point2::new(1, 1),
+            Dir8::S => Point2::new(0, 1),
+            Dir8::SW => Point2::new(-1, 1),
+            Dir8::W => Point2::new(-1, 0),
+            Dir8::NW => Point2::new(-1, -1),
+        }
+    }
+
+    /// Rotates 45 degrees clockwise
+    pub fn rotate_cw(self) -> Dir8 {
+        Dir8::ALL[(self.index() + 1) % 8]
+    }
+
+    /// Rotates 45 degrees counter-clockwise
+    pub fn rotate_ccw(self) -> Dir8 {
+        Dir8::ALL[(self.index() + 7) % 8]
+    }
+
+    /// Returns the direction pointing the other way
+    pub fn opposite(self) -> Dir8 {
+        Dir8::ALL[(self.index() + 4) % 8]
+    }
+
+    /// Position of this direction in `Dir8::ALL`
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        match dir {
+            Dir4::Up => Dir8::N,
+            Dir4::Right => Dir8::E,
+            Dir4::Down => Dir8::S,
+            Dir4::Left => Dir8::W,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dir4_rotations_and_opposites() {
+        for dir in Dir4::ALL {
+            assert_eq!(dir.rotate_cw().rotate_ccw(), dir);
+            assert_eq!(dir.rotate_cw().rotate_cw(), dir.opposite());
+            assert_eq!(dir.opposite().delta(), -dir.delta());
+        }
+        assert_eq!(Dir4::Up.rotate_cw(), Dir4::Right);
+        assert_eq!(Dir4::Up.rotate_ccw(), Dir4::Left);
+        assert_eq!(Dir4::Left.opposite(), Dir4::Right);
+    }
+
+    #[test]
+    fn parses_both_direction_notations() {
+        let letters: Vec<Dir4> = "URDL".chars().map(|c| c.try_into().unwrap()).collect();
+        let arrows: Vec<Dir4> = "^>v<".chars().map(|c| c.try_into().unwrap()).collect();
+        assert_eq!(letters, Dir4::ALL);
+        assert_eq!(arrows, Dir4::ALL);
+
+        assert!(Dir4::try_from('u').is_err());
+        assert!(Dir4::try_from('V').is_err());
+        assert_eq!("L".parse::<Dir4>().unwrap(), Dir4::Left);
+        assert!("LR".parse::<Dir4>().is_err());
+    }
+
+    #[test]
+    fn dir8_neighbours_surround_the_point() {
+        let p = Point2::new(3, -2);
+        let neighbours = p.neighbors8();
+        assert_eq!(neighbours[0], Point2::new(3, -3));
+        assert_eq!(neighbours[3], Point2::new(4, -1));
+        for (dir, n) in Dir8::ALL.into_iter().zip(neighbours) {
+            assert_eq!(p.chebyshev(n), 1);
+            assert_eq!(n - dir.delta(), p);
+            assert_eq!(dir.rotate_cw().rotate_ccw(), dir);
+            assert_eq!(dir.opposite().opposite(), dir);
+        }
+        for dir in Dir4::ALL {
+            assert!(neighbours.contains(&p.step(dir)));
+            assert_eq!(Dir8::from(dir).delta(), dir.delta());
+        }
+    }
+
+    #[test]
+    fn grid_indices_stay_in_bounds() {
+        assert_eq!(Point2::from_grid(2, 5), Point2::new(5, 2));
+        assert_eq!(Point2::new(5, 2).to_grid(3, 6), Some((2, 5)));
+        assert_eq!(Point2::new(6, 2).to_grid(3, 6), None);
+        assert_eq!(Point2::new(5, 3).to_grid(3, 6), None);
+        assert_eq!(Point2::new(-1, 0).to_grid(3, 6), None);
+        assert_eq!(Point2::new(0, -1).to_grid(3, 6), None);
+    }
+
+    #[test]
+    fn distances() {
+        let a = Point2::new(1, -2);
+        let b = Point2::new(-3, 4);
+        assert_eq!(a.manhattan(b), 10);
+        assert_eq!(a.chebyshev(b), 6);
+        assert_eq!(Point3::new(1, 2, 3).manhattan(Point3::ORIGIN), 6);
+        assert_eq!(Point3::new(1, -5, 3).chebyshev(Point3::ORIGIN), 5);
+    }
+}
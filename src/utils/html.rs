@@ -0,0 +1,92 @@
+//! Just enough HTML handling to read Advent of Code puzzle and answer pages
+
+/// Returns the inner HTML of every top-level `<tag …>…</tag>` element
+pub fn elements<'a>(html: &'a str, tag: &str) -> Vec<&'a str> {
+    let open = format!("<{}", tag);
+    let close = format!("</{}>", tag);
+    let mut found = Vec::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find(&open) {
+        let after_name = &rest[start + open.len()..];
+        // Skip longer tag names that share the prefix (e.g. `<pre>` vs `<prefix>`)
+        if !after_name.starts_with(['>', ' ', '\t', '\n']) {
+            rest = after_name;
+            continue;
+        }
+        let Some(body_start) = after_name.find('>') else {
+            break;
+        };
+        let body = &after_name[body_start + 1..];
+        let Some(end) = body.find(&close) else {
+            break;
+        };
+        found.push(&body[..end]);
+        rest = &body[end + close.len()..];
+    }
+
+    found
+}
+
+/// Converts an HTML fragment to its text: tags removed, entities decoded
+///
+/// Whitespace is kept as is, so `<pre>` blocks keep their layout.
+pub fn to_text(html: &str) -> String {
+    decode_entities(&strip_tags(html))
+}
+
+/// Removes every `<…>` tag, keeping the text between them
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+/// Decodes the HTML entities that appear in puzzle text
+pub fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        // Last, so `&amp;lt;` decodes to `&lt;` rather than `<`
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_elements_by_exact_tag_name() {
+        let html = r#"<prefix>no</prefix><pre class="x">one</pre> <pre>two</pre>"#;
+        assert_eq!(elements(html, "pre"), ["one", "two"]);
+        assert!(elements("<pre>unclosed", "pre").is_empty());
+    }
+
+    #[test]
+    fn text_keeps_layout_and_decodes_entities() {
+        assert_eq!(
+            to_text("<code>a &lt; b\n  <em>c</em> &amp; d</code>"),
+            "a < b\n  c & d"
+        );
+    }
+
+    #[test]
+    fn decodes_each_entity_once() {
+        assert_eq!(
+            decode_entities("&quot;x&quot; &#39;y&apos;&nbsp;&gt;"),
+            "\"x\" 'y' >"
+        );
+        assert_eq!(decode_entities("&amp;lt;"), "&lt;");
+    }
+}
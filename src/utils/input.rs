@@ -1,53 +1,260 @@
+use crate::utils::calendar;
+use crate::utils::crypto;
 use crate::utils::error::{AocError, Result};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+use tracing::{debug, instrument};
 
-/// Reads the input file for the specified day and part
+/// Directory holding the per-year input directories, unless configured otherwise
+pub const DEFAULT_INPUT_DIR: &str = "inputs";
+
+/// The configured input directory (`None` for `DEFAULT_INPUT_DIR`)
+static INPUT_DIR: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+/// Changes the directory `input_path` builds paths under (`input_dir` in `aoc.toml`)
+pub fn set_input_dir(dir: impl Into<PathBuf>) {
+    let mut input_dir = INPUT_DIR.write().unwrap_or_else(|e| e.into_inner());
+    *input_dir = Some(dir.into());
+}
+
+/// The directory `input_path` builds paths under
+pub fn input_dir() -> PathBuf {
+    let input_dir = INPUT_DIR.read().unwrap_or_else(|e| e.into_inner());
+    input_dir
+        .clone()
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_INPUT_DIR))
+}
+
+/// Builds the path of the input file for the specified year, day and part
+///
+/// Each event year has its own directory under `input_dir()` (`inputs/2025/`
+/// by default).
 ///
 /// # Arguments
-/// * `day` - The day number (1-25)
+/// * `year` - The event year
+/// * `day` - The day number (within the year's `calendar::valid_days`)
 /// * `part` - The part number (1 or 2)
-/// * `use_sample` - If true, reads from sample file (day1p1s.txt format), 
-///                  if false, reads from real file (day01p1.txt format)
+/// * `use_sample` - If true, points at the sample file (day1p1s.txt format),
+///   if false, points at the real file (day01p1.txt format)
 ///
 /// # Returns
-/// The contents of the input file as a String
+/// The path of the input file (which may not exist), relative unless
+/// the input directory is absolute
 ///
 /// # Errors
-/// Returns `AocError::InvalidDay` if day is not between 1 and 25
-/// Returns `AocError::InputNotFound` if the input file doesn't exist
-/// Returns `AocError::IoError` if there's an error reading the file
-pub fn read_input(day: u8, part: u8, use_sample: bool) -> Result<String> {
-    // Validate day number
-    if day < 1 || day > 25 {
-        return Err(AocError::InvalidDay(day));
-    }
-
-    // Validate part number
-    if part != 1 && part != 2 {
-        return Err(AocError::ParseError(format!(
-            "Invalid part number: {}. Part must be 1 or 2.",
-            part
-        )));
-    }
+/// Returns `AocError::InvalidYear` or `AocError::InvalidDay` if the year has
+/// no event or the day is not part of it
+/// Returns `AocError::ParseError` if part is not 1 or 2
+/// Returns `AocError::SinglePartDay` for part 2 of the event's last day
+pub fn input_path(year: u16, day: u8, part: u8, use_sample: bool) -> Result<PathBuf> {
+    // Validate year, day and part number
+    calendar::check_part(year, day, part)?;
 
-    // Construct file path based on whether we're using sample or real input
-    let file_path = if use_sample {
+    // Construct file name based on whether we're using sample or real input
+    let file_name = if use_sample {
         // Sample files: day1p1s.txt, day1p2s.txt, etc. (no leading zero, 's' suffix)
-        format!("inputs/day{}p{}s.txt", day, part)
+        format!("day{}p{}s.txt", day, part)
     } else {
         // Real files: day01p1.txt, day01p2.txt, etc. (with leading zero)
-        format!("inputs/day{:02}p{}.txt", day, part)
+        format!("day{:02}p{}.txt", day, part)
     };
-    
-    let path = PathBuf::from(&file_path);
 
-    // Check if file exists
+    Ok(input_dir().join(year.to_string()).join(file_name))
+}
+
+/// Builds the path of the expected-answer sidecar for an input file
+///
+/// The sidecar sits next to the input with an `.expected` extension
+/// (e.g. `inputs/2025/day4p1s.expected`) and holds the answer on one line.
+///
+/// # Errors
+/// Same as `input_path`
+pub fn expected_path(year: u16, day: u8, part: u8, use_sample: bool) -> Result<PathBuf> {
+    Ok(input_path(year, day, part, use_sample)?.with_extension("expected"))
+}
+
+/// Reads the answer recorded in an input's `.expected` sidecar
+///
+/// # Returns
+/// The trimmed answer, or `None` if there is no sidecar or it is empty
+pub fn read_expected(year: u16, day: u8, part: u8, use_sample: bool) -> Option<String> {
+    let path = expected_path(year, day, part, use_sample).ok()?;
+    let answer = fs::read_to_string(path).ok()?;
+    Some(answer.trim().to_string()).filter(|answer| !answer.is_empty())
+}
+
+/// Reads the input file for the specified year, day and part
+///
+/// # Arguments
+/// * `year` - The event year
+/// * `day` - The day number (within the year's `calendar::valid_days`)
+/// * `part` - The part number (1 or 2)
+/// * `use_sample` - If true, reads from sample file (day1p1s.txt format),
+///   if false, reads from real file (day01p1.txt format)
+///
+/// If the plain file is missing but an age-encrypted copy (`day01p1.txt.age`)
+/// exists, it is decrypted transparently with the key from `crypto::load_identity`.
+///
+/// # Returns
+/// The contents of the input file as a String
+///
+/// # Errors
+/// Returns `AocError::InvalidYear` or `AocError::InvalidDay` for a year or day outside the calendar
+/// Returns `AocError::InputNotFound` if neither the input file nor an encrypted copy exists
+/// Returns `AocError::IoError` if there's an error reading the file
+/// Returns `AocError::CryptoError` if an encrypted copy can't be decrypted
+#[instrument(level = "debug")]
+pub fn read_input(year: u16, day: u8, part: u8, use_sample: bool) -> Result<String> {
+    read_input_file(&input_path(year, day, part, use_sample)?, day)
+}
+
+/// Reads one input file, falling back to its encrypted copy (see `read_input`)
+fn read_input_file(path: &Path, day: u8) -> Result<String> {
+    // Check if file exists, falling back to an encrypted copy
     if !path.exists() {
-        return Err(AocError::InputNotFound(day, file_path));
+        let encrypted = crypto::encrypted_path(path);
+        if encrypted.exists() {
+            let input = crypto::decrypt_file(&encrypted, &crypto::load_identity()?)?;
+            debug!(path = %encrypted.display(), bytes = input.len(), "decrypted input");
+            return Ok(input);
+        }
+        return Err(AocError::InputNotFound(day, path.display().to_string()));
     }
 
     // Read and return file contents
-    fs::read_to_string(&path).map_err(AocError::from)
+    let input = fs::read_to_string(path)?;
+    debug!(path = %path.display(), bytes = input.len(), "read input");
+    Ok(input)
+}
+
+/// A block of consecutive non-blank lines from a multi-section input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section {
+    /// 1-based line number of the section's first line in the original input
+    pub start_line: usize,
+    /// The section's lines joined with `\n` (no trailing newline)
+    pub text: String,
+}
+
+impl Section {
+    /// Iterates over the section's lines paired with their 1-based line numbers
+    pub fn numbered_lines(&self) -> impl Iterator<Item = (usize, &str)> {
+        self.text
+            .lines()
+            .enumerate()
+            .map(move |(idx, line)| (self.start_line + idx, line))
+    }
+}
+
+/// Splits an input into sections separated by blank lines
+///
+/// Lines end at `\n` or `\r\n`, and lines containing only whitespace count
+/// as blank, so inputs saved on Windows or with stray indentation split the
+/// same way. Runs of several blank lines never produce empty sections.
+/// Line numbers are counted like `ParseDiagnostic` counts them, so a
+/// section's lines can be reported with `ParseDiagnostic::at_line`.
+///
+/// # Arguments
+/// * `input` - The raw input text
+///
+/// # Returns
+/// The sections in input order, each tagged with its starting line number
+pub fn sections(input: &str) -> Vec<Section> {
+    let mut sections = Vec::new();
+    let mut current: Option<Section> = None;
+
+    for (idx, line) in input.lines().enumerate() {
+        if line.trim().is_empty() {
+            sections.extend(current.take());
+            continue;
+        }
+
+        match current.as_mut() {
+            Some(section) => {
+                section.text.push('\n');
+                section.text.push_str(line);
+            }
+            None => {
+                current = Some(Section {
+                    start_line: idx + 1,
+                    text: line.to_string(),
+                })
+            }
+        }
+    }
+    sections.extend(current);
+
+    sections
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::error::ParseDiagnostic;
+    use age::secrecy::ExposeSecret;
+    use age::x25519::Identity;
+
+    #[test]
+    fn falls_back_to_the_encrypted_copy() {
+        // The only test that reads the key from the environment
+        let identity = Identity::generate();
+        std::env::set_var(crypto::KEY_ENV, identity.to_string().expose_secret());
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("day01p1.txt");
+        let ciphertext = crypto::encrypt(&identity, "L68\nR48\n").unwrap();
+        fs::write(crypto::encrypted_path(&path), ciphertext).unwrap();
+        assert_eq!(read_input_file(&path, 1).unwrap(), "L68\nR48\n");
+
+        // A plain file takes precedence over the encrypted copy
+        fs::write(&path, "plain").unwrap();
+        assert_eq!(read_input_file(&path, 1).unwrap(), "plain");
+    }
+
+    #[test]
+    fn splits_on_blank_and_whitespace_only_lines() {
+        let input = "a\nb\n\n \t\nc\n\n\n\nd\n";
+        let sections = sections(input);
+        let texts: Vec<&str> = sections.iter().map(|s| s.text.as_str()).collect();
+        assert_eq!(texts, ["a\nb", "c", "d"]);
+        let starts: Vec<usize> = sections.iter().map(|s| s.start_line).collect();
+        assert_eq!(starts, [1, 5, 9]);
+    }
+
+    #[test]
+    fn crlf_input_splits_like_lf() {
+        let lf = sections("1-3\n5-7\n\n4\n");
+        assert_eq!(sections("1-3\r\n5-7\r\n\r\n4\r\n"), lf);
+        assert_eq!(
+            lf[1].numbered_lines().collect::<Vec<_>>(),
+            [(4, "4")],
+            "numbered from the section's start line"
+        );
+    }
+
+    #[test]
+    fn section_lines_match_diagnostic_lines() {
+        // A lone `\r` is text, not a line break, for both
+        let input = "1-3\r\nx\ry\r\n\r\n  4\r\n 5z\r\n";
+        let section = &sections(input)[1];
+        let (line_no, line) = section.numbered_lines().nth(1).unwrap();
+        let line = line.trim();
+        let diagnostic =
+            ParseDiagnostic::at_line(input, line_no, line, &line[1..], "bad", "a digit");
+        assert_eq!((diagnostic.line, diagnostic.column), (5, 3));
+        assert!(diagnostic
+            .render(input)
+            .contains("5 |  5z\n  |   ^ expected a digit"));
+
+        let first = &sections(input)[0];
+        assert_eq!(first.numbered_lines().last(), Some((2, "x\ry")));
+    }
+
+    #[test]
+    fn reports_a_missing_input() {
+        let dir = tempfile::tempdir().unwrap();
+        let err = read_input_file(&dir.path().join("day01p1.txt"), 1).unwrap_err();
+        assert!(matches!(err, AocError::InputNotFound(1, _)), "{err:?}");
+    }
+}
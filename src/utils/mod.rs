@@ -1,6 +1,15 @@
+pub mod calendar;
+pub mod cancel;
+pub mod crypto;
 pub mod error;
+pub mod geom;
+pub mod html;
 pub mod input;
+pub mod panic;
+pub mod ranges;
 
-pub use error::{AocError, Result};
-pub use input::read_input;
-
+pub use cancel::{checkpoint, CancelToken};
+pub use error::{AocError, ParseDiagnostic, Result};
+pub use geom::{Dir4, Dir8, Point2, Point3};
+pub use input::{expected_path, input_path, read_expected, read_input, sections, Section};
+pub use ranges::RangeSet;
@@ -0,0 +1,101 @@
+use crate::utils::error::{AocError, Result};
+use std::cell::{Cell, RefCell};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+
+thread_local! {
+    /// How many `catch` calls are running on this thread
+    static CATCHING: Cell<usize> = const { Cell::new(0) };
+    /// Message and location of the last panic caught on this thread
+    static LAST_PANIC: RefCell<Option<(String, String)>> = const { RefCell::new(None) };
+}
+
+static INSTALL_HOOK: Once = Once::new();
+
+/// Runs a solver, turning a panic into `AocError::SolverPanic`
+///
+/// While `f` runs, the panic hook records the message and source location
+/// instead of printing them (and a backtrace) to stderr. Panics outside of
+/// `catch` still go to the previous hook as usual.
+///
+/// # Errors
+/// Returns whatever `f` returns, or `AocError::SolverPanic` if it panicked
+pub fn catch<T>(f: impl FnOnce() -> Result<T>) -> Result<T> {
+    INSTALL_HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING.with(Cell::get) == 0 {
+                return previous(info);
+            }
+            let payload = info.payload();
+            let message = payload
+                .downcast_ref::<&str>()
+                .copied()
+                .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+                .unwrap_or("unknown panic payload");
+            let location = info.location().map_or_else(
+                || "an unknown location".to_string(),
+                |location| location.to_string(),
+            );
+            LAST_PANIC.with(|last| *last.borrow_mut() = Some((message.to_string(), location)));
+        }));
+    });
+
+    CATCHING.with(|depth| depth.set(depth.get() + 1));
+    let outcome = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.with(|depth| depth.set(depth.get() - 1));
+
+    outcome.unwrap_or_else(|_| {
+        let (message, location) = LAST_PANIC
+            .with(|last| last.borrow_mut().take())
+            .unwrap_or_else(|| {
+                (
+                    "unknown panic payload".to_string(),
+                    "an unknown location".to_string(),
+                )
+            });
+        Err(AocError::SolverPanic(message, location))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_the_message_and_location() {
+        let line = line!() + 1;
+        let caught = catch(|| -> Result<()> { panic!("bad input {}", 42) });
+        match caught {
+            Err(AocError::SolverPanic(message, location)) => {
+                assert_eq!(message, "bad input 42");
+                assert!(location.starts_with(&format!("{}:{}:", file!(), line)));
+            }
+            other => panic!("expected a SolverPanic, got {:?}", other),
+        }
+
+        let caught = catch(|| -> Result<()> { panic!("plain message") });
+        assert!(matches!(caught, Err(AocError::SolverPanic(m, _)) if m == "plain message"));
+    }
+
+    #[test]
+    fn passes_results_through() {
+        assert_eq!(catch(|| Ok(5)).unwrap(), 5);
+        let failed = catch(|| -> Result<()> { Err(AocError::SolutionError("no".to_string())) });
+        assert!(matches!(failed, Err(AocError::SolutionError(_))));
+    }
+
+    #[test]
+    fn nested_catches_report_their_own_panic() {
+        let outer = catch(|| -> Result<()> {
+            let inner = catch(|| -> Result<()> { panic!("inner") });
+            assert!(matches!(&inner, Err(AocError::SolverPanic(m, _)) if m == "inner"));
+            // Still catching after the inner call returns
+            panic!("outer");
+        });
+        assert!(matches!(outer, Err(AocError::SolverPanic(m, _)) if m == "outer"));
+
+        let recovered = catch(|| catch(|| -> Result<u8> { panic!("inner") }).or(Ok(7)));
+        assert_eq!(recovered.unwrap(), 7);
+    }
+}
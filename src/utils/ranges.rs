@@ -0,0 +1,265 @@
+use crate::utils::error::{AocError, Result};
+use std::ops::RangeInclusive;
+
+/// A set of `u64` values stored as sorted, disjoint inclusive ranges
+///
+/// Overlapping and adjacent ranges are merged on construction, so
+/// membership queries are a binary search over the merged ranges.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RangeSet {
+    /// Sorted, non-overlapping, non-adjacent inclusive `(start, end)` pairs
+    ranges: Vec<(u64, u64)>,
+}
+
+impl RangeSet {
+    /// Creates an empty set
+    pub fn new() -> Self {
+        RangeSet { ranges: Vec::new() }
+    }
+
+    /// Builds a set from inclusive `(start, end)` ranges
+    ///
+    /// # Errors
+    /// Returns `AocError::ParseError` for a reversed range (`start > end`)
+    pub fn from_inclusive<I>(ranges: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = (u64, u64)>,
+    {
+        let ranges = ranges
+            .into_iter()
+            .map(|(start, end)| check_order(start, end, "..="))
+            .collect::<Result<_>>()?;
+
+        Ok(RangeSet {
+            ranges: merge(ranges),
+        })
+    }
+
+    /// Builds a set from half-open `(start, end)` ranges (`end` excluded)
+    ///
+    /// A range with `start == end` is empty and contributes nothing.
+    ///
+    /// # Errors
+    /// Returns `AocError::ParseError` for a reversed range (`start > end`)
+    pub fn from_half_open<I>(ranges: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = (u64, u64)>,
+    {
+        let mut inclusive = Vec::new();
+        for (start, end) in ranges {
+            check_order(start, end, "..")?;
+            if start < end {
+                inclusive.push((start, end - 1));
+            }
+        }
+        RangeSet::from_inclusive(inclusive)
+    }
+
+    /// Adds the inclusive range `start..=end` to the set
+    ///
+    /// # Errors
+    /// Returns `AocError::ParseError` if `start > end`
+    pub fn insert(&mut self, start: u64, end: u64) -> Result<()> {
+        check_order(start, end, "..=")?;
+
+        let mut ranges = std::mem::take(&mut self.ranges);
+        ranges.push((start, end));
+        self.ranges = merge(ranges);
+        Ok(())
+    }
+
+    /// Checks whether `value` is in the set
+    pub fn contains(&self, value: u64) -> bool {
+        self.range_containing(value).is_some()
+    }
+
+    /// Returns the merged range that contains `value`, if any
+    pub fn range_containing(&self, value: u64) -> Option<RangeInclusive<u64>> {
+        // First range that doesn't end before `value`
+        let idx = self.ranges.partition_point(|&(_, end)| end < value);
+        self.ranges
+            .get(idx)
+            .filter(|&&(start, _)| start <= value)
+            .map(|&(start, end)| start..=end)
+    }
+
+    /// Total number of values in the set
+    ///
+    /// Returned as `u128` because the full `u64` domain holds 2^64 values.
+    pub fn len(&self) -> u128 {
+        self.ranges
+            .iter()
+            .map(|&(start, end)| (end - start) as u128 + 1)
+            .sum()
+    }
+
+    /// Checks whether the set holds no values
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Iterates over the merged ranges in ascending order
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<u64>> + '_ {
+        self.ranges.iter().map(|&(start, end)| start..=end)
+    }
+
+    /// Returns the values that are in either set
+    pub fn union(&self, other: &RangeSet) -> RangeSet {
+        RangeSet {
+            ranges: merge(self.ranges.iter().chain(&other.ranges).copied().collect()),
+        }
+    }
+
+    /// Returns the values that are in both sets
+    pub fn intersection(&self, other: &RangeSet) -> RangeSet {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a_start, a_end) = self.ranges[i];
+            let (b_start, b_end) = other.ranges[j];
+
+            let start = a_start.max(b_start);
+            let end = a_end.min(b_end);
+            if start <= end {
+                ranges.push((start, end));
+            }
+
+            // Advance whichever range finishes first
+            if a_end < b_end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        RangeSet { ranges }
+    }
+
+    /// Returns the values that are in `self` but not in `other`
+    pub fn difference(&self, other: &RangeSet) -> RangeSet {
+        self.intersection(&other.complement(0..=u64::MAX))
+    }
+
+    /// Returns the values within `bounds` that are not in the set
+    pub fn complement(&self, bounds: RangeInclusive<u64>) -> RangeSet {
+        let (low, high) = bounds.into_inner();
+        let mut ranges = Vec::new();
+        if low > high {
+            return RangeSet { ranges };
+        }
+
+        let mut cursor = low;
+        for &(start, end) in &self.ranges {
+            if end < low {
+                continue;
+            }
+            if start > high {
+                break;
+            }
+
+            if start > cursor {
+                ranges.push((cursor, start - 1));
+            }
+
+            // `end + 1` can't overflow here since `end < high <= u64::MAX`
+            if end >= high {
+                return RangeSet { ranges };
+            }
+            cursor = end + 1;
+        }
+
+        ranges.push((cursor, high));
+        RangeSet { ranges }
+    }
+}
+
+/// Rejects a range whose start comes after its end
+fn check_order(start: u64, end: u64, notation: &str) -> Result<(u64, u64)> {
+    if start > end {
+        return Err(AocError::ParseError(format!(
+            "Reversed range {}{}{}: start is after end",
+            start, notation, end
+        )));
+    }
+    Ok((start, end))
+}
+
+/// Sorts ranges and merges any that overlap or touch
+fn merge(mut ranges: Vec<(u64, u64)>) -> Vec<(u64, u64)> {
+    ranges.sort_unstable();
+
+    let mut merged: Vec<(u64, u64)> = Vec::with_capacity(ranges.len());
+    for (start, end) in ranges {
+        match merged.last_mut() {
+            // Saturating so a range ending at `u64::MAX` absorbs everything after it
+            Some(last) if start <= last.1.saturating_add(1) => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ranges(set: &RangeSet) -> Vec<(u64, u64)> {
+        set.iter().map(|range| range.into_inner()).collect()
+    }
+
+    #[test]
+    fn merges_overlapping_and_adjacent_ranges() {
+        let set = RangeSet::from_inclusive([(10, 14), (3, 5), (16, 20), (12, 18), (6, 7)]).unwrap();
+        assert_eq!(ranges(&set), vec![(3, 7), (10, 20)]);
+        assert_eq!(set.len(), 16);
+    }
+
+    #[test]
+    fn contains_uses_merged_ranges() {
+        let set = RangeSet::from_inclusive([(3, 5), (10, 14), (16, 20), (12, 18)]).unwrap();
+        assert!(set.contains(3));
+        assert!(set.contains(17));
+        assert!(!set.contains(9));
+        assert!(!set.contains(21));
+        assert_eq!(set.range_containing(15), Some(10..=20));
+    }
+
+    #[test]
+    fn handles_the_ends_of_the_u64_domain() {
+        let set =
+            RangeSet::from_inclusive([(u64::MAX - 1, u64::MAX), (0, 0), (5, u64::MAX)]).unwrap();
+        assert_eq!(ranges(&set), vec![(0, 0), (5, u64::MAX)]);
+
+        let everything = RangeSet::from_inclusive([(0, u64::MAX)]).unwrap();
+        assert_eq!(everything.len(), 1 << 64);
+        assert!(everything.complement(0..=u64::MAX).is_empty());
+    }
+
+    #[test]
+    fn rejects_reversed_ranges() {
+        assert!(RangeSet::from_inclusive([(1, 2), (5, 4)]).is_err());
+        assert!(RangeSet::from_half_open([(5, 4)]).is_err());
+        assert!(RangeSet::new().insert(5, 4).is_err());
+    }
+
+    #[test]
+    fn half_open_ranges_exclude_their_end() {
+        let set = RangeSet::from_half_open([(1, 4), (4, 4), (4, 6)]).unwrap();
+        assert_eq!(ranges(&set), vec![(1, 5)]);
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = RangeSet::from_inclusive([(1, 10), (20, 30)]).unwrap();
+        let b = RangeSet::from_inclusive([(5, 25)]).unwrap();
+        assert_eq!(ranges(&a.union(&b)), vec![(1, 30)]);
+        assert_eq!(ranges(&a.intersection(&b)), vec![(5, 10), (20, 25)]);
+        assert_eq!(ranges(&a.difference(&b)), vec![(1, 4), (26, 30)]);
+        assert_eq!(
+            ranges(&a.complement(0..=35)),
+            vec![(0, 0), (11, 19), (31, 35)]
+        );
+    }
+}
@@ -0,0 +1,122 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// How often watched files are polled for changes
+pub const POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+/// Polls a set of files and reports which ones changed
+///
+/// Changes are detected by modification time, so creating or deleting a
+/// file counts too. Polling keeps the tool free of platform-specific
+/// notification APIs and works the same on network drives and in containers.
+pub struct FileWatcher {
+    paths: Vec<PathBuf>,
+    stamps: Vec<Option<SystemTime>>,
+}
+
+impl FileWatcher {
+    /// Starts watching `paths` (which don't need to exist yet)
+    pub fn new(paths: Vec<PathBuf>) -> Self {
+        let stamps = paths.iter().map(|path| modified(path)).collect();
+        FileWatcher { paths, stamps }
+    }
+
+    /// The watched files
+    pub fn paths(&self) -> &[PathBuf] {
+        &self.paths
+    }
+
+    /// Blocks until at least one watched file changes
+    ///
+    /// Once a change is seen it waits one more poll interval so an editor
+    /// that saves in several writes triggers a single rerun.
+    ///
+    /// # Returns
+    /// The files that changed
+    pub fn wait_for_change(&mut self) -> Vec<PathBuf> {
+        loop {
+            thread::sleep(POLL_INTERVAL);
+            if self.changed().is_empty() {
+                continue;
+            }
+
+            thread::sleep(POLL_INTERVAL);
+            let changed = self.changed();
+            self.stamps = self.paths.iter().map(|path| modified(path)).collect();
+            if !changed.is_empty() {
+                return changed;
+            }
+        }
+    }
+
+    /// Files whose modification time differs from the last snapshot
+    fn changed(&self) -> Vec<PathBuf> {
+        self.paths
+            .iter()
+            .zip(&self.stamps)
+            .filter(|(path, stamp)| modified(path) != **stamp)
+            .map(|(path, _)| path.clone())
+            .collect()
+    }
+}
+
+/// Modification time of a file, or `None` if it doesn't exist
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// Extracts the answers from the output of a day run
+///
+/// Answers follow their `=== Day N Part P ===` header as an `Answer: …` line.
+///
+/// # Returns
+/// `(part, answer)` pairs in output order
+pub fn parse_answers(output: &str) -> Vec<(u8, String)> {
+    let mut part = None;
+    let mut answers = Vec::new();
+
+    for line in output.lines() {
+        if let Some(header) = line.strip_prefix("=== Day ") {
+            part = header
+                .split(" Part ")
+                .nth(1)
+                .and_then(|rest| rest.trim_end_matches(" ===").parse().ok());
+        } else if let (Some(answer), Some(p)) = (line.strip_prefix("Answer: "), part) {
+            answers.push((p, answer.trim().to_string()));
+        }
+    }
+
+    answers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_each_parts_answer() {
+        let output = "=== Day 4 Part 1 ===\nAnswer: 13\n\n=== Day 4 Part 2 ===\nAnswer: 43 \n";
+        assert_eq!(
+            parse_answers(output),
+            [(1, "13".to_string()), (2, "43".to_string())]
+        );
+    }
+
+    #[test]
+    fn skips_parts_without_an_answer() {
+        let output =
+            "=== Day 1 Part 1 ===\nAnswer: 3\nMemory: 1 allocation, 1 B allocated, 1 B peak\n\n\
+                      === Day 1 Part 2 ===\nUnable to locate part 2 files.\n";
+        assert_eq!(parse_answers(output), [(1, "3".to_string())]);
+    }
+
+    #[test]
+    fn ignores_answers_outside_a_part() {
+        let output =
+            "Answer: 1\n=== Day 1 Part x ===\nAnswer: 2\n=== Day 1 Part 2 ===\nAnswer: 3\n";
+        assert_eq!(parse_answers(output), [(2, "3".to_string())]);
+        assert!(parse_answers("").is_empty());
+    }
+}